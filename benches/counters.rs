@@ -0,0 +1,62 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_euklid::{CvRDT, GCounter, PNCounter, Snapshot};
+
+const SEED: u64 = 0x5eed;
+
+/// A small linear congruential generator, so every run benchmarks the same counters.
+fn pairs(seed: u64, actors: u64) -> Vec<(u64, usize)> {
+    let mut state = seed;
+    (0..actors)
+        .map(|a| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (a, ((state >> 33) % 1_000) as usize)
+        })
+        .collect()
+}
+
+fn bench_gcounter_incr(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gcounter_incr_assign");
+    for actors in [1u64, 8] {
+        group.bench_with_input(BenchmarkId::from_parameter(actors), &actors, |b, actors| {
+            b.iter(|| {
+                let mut counter = GCounter::<u64, usize>::new();
+                for i in 0..1_000 {
+                    counter.incr_assign(&(i % actors));
+                }
+                black_box(counter)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_pncounter_merge(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pncounter_merge");
+    for actors in [10u64, 100] {
+        let counter =
+            PNCounter::<u64, usize>::restore((pairs(SEED, actors), pairs(SEED + 1, actors)).into())
+                .unwrap();
+        let other = PNCounter::<u64, usize>::restore(
+            (pairs(SEED + 2, actors), pairs(SEED + 3, actors)).into(),
+        )
+        .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(actors),
+            &(counter, other),
+            |b, (counter, other)| {
+                b.iter(|| {
+                    let mut counter = counter.clone();
+                    counter.merge(other.clone());
+                    black_box(counter)
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_gcounter_incr, bench_pncounter_merge);
+criterion_main!(benches);
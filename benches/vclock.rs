@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_euklid::{CmRDT, CvRDT, Dot, SmallVClock, VClock};
+
+const SEED: u64 = 0x5eed;
+
+/// A small linear congruential generator, so every run benchmarks the same clocks.
+fn pairs(seed: u64, actors: u64) -> Vec<(u64, u64)> {
+    let mut state = seed;
+    (0..actors)
+        .map(|a| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (a, (state >> 33) % 1_000)
+        })
+        .collect()
+}
+
+fn log(len: u64, actors: u8) -> Vec<Dot<u8, u64>> {
+    (0..len)
+        .map(|i| Dot::new((i % actors as u64) as u8, i))
+        .collect()
+}
+
+fn bench_apply(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_replay");
+    for len in [100u64, 1_000, 10_000] {
+        let dots = log(len, 8);
+
+        group.bench_with_input(BenchmarkId::new("apply", len), &dots, |b, dots| {
+            b.iter(|| {
+                let mut clock = VClock::<u8, u64>::default();
+                for dot in dots.iter() {
+                    clock.apply(*dot);
+                }
+                black_box(clock)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("apply_all", len), &dots, |b, dots| {
+            b.iter(|| {
+                let mut clock = VClock::<u8, u64>::default();
+                clock.apply_all(dots.iter().copied());
+                black_box(clock)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_clone");
+    for actors in [10u64, 100, 1_000] {
+        let clock: VClock<u64, u64> = (0..actors).map(|a| Dot::new(a, a)).collect();
+
+        group.bench_with_input(BenchmarkId::from_parameter(actors), &clock, |b, clock| {
+            b.iter(|| black_box(clock.clone()))
+        });
+    }
+    group.finish();
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_encode");
+    for actors in [3u64, 10] {
+        let clock: VClock<u64, u64> = (0..actors).map(|a| Dot::new(a, 1_000 + a)).collect();
+
+        let compact = clock.encode_compact().len();
+        let json = serde_json::to_vec(&clock.to_vec()).unwrap().len();
+        println!("vclock_encode/{actors}: compact {compact} bytes, json {json} bytes");
+
+        group.bench_with_input(BenchmarkId::new("compact", actors), &clock, |b, clock| {
+            b.iter(|| black_box(clock.encode_compact()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("json", actors), &clock, |b, clock| {
+            b.iter(|| black_box(serde_json::to_vec(&clock.to_vec()).unwrap()))
+        });
+    }
+    group.finish();
+}
+
+fn bench_merge(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_merge");
+    for actors in [1_000u64, 10_000] {
+        let clocks: Vec<VClock<u64, u64>> = (0..8u64)
+            .map(|i| {
+                (0..actors)
+                    .map(|a| Dot::new(a, (a * 7 + i * 13) % 100))
+                    .collect()
+            })
+            .collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("sequential", actors),
+            &clocks,
+            |b, clocks| {
+                b.iter(|| {
+                    let mut clock = VClock::<u64, u64>::default();
+                    for other in clocks.iter() {
+                        clock.merge(other.clone());
+                    }
+                    black_box(clock)
+                })
+            },
+        );
+
+        #[cfg(feature = "rayon")]
+        group.bench_with_input(
+            BenchmarkId::new("parallel", actors),
+            &clocks,
+            |b, clocks| {
+                b.iter(|| {
+                    let mut clock = VClock::<u64, u64>::default();
+                    clock.parallel_merge(clocks.clone());
+                    black_box(clock)
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_merge_sizes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_merge_sizes");
+    for actors in [1u64, 10, 100, 1_000] {
+        let clock: VClock<u64, u64> = pairs(SEED, actors).into();
+        let other: VClock<u64, u64> = pairs(SEED + 1, actors).into();
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(actors),
+            &(clock, other),
+            |b, (clock, other)| {
+                b.iter(|| {
+                    let mut clock = clock.clone();
+                    clock.merge(other.clone());
+                    black_box(clock)
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_apply_dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_apply_dot");
+    for actors in [10u64, 1_000] {
+        let clock: VClock<u64, u64> = pairs(SEED, actors).into();
+        let dot = Dot::new(actors / 2, 1_000);
+
+        group.bench_with_input(BenchmarkId::from_parameter(actors), &clock, |b, clock| {
+            b.iter(|| {
+                let mut clock = clock.clone();
+                clock.apply(dot);
+                black_box(clock)
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_baseline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_baseline");
+    for actors in [10u64, 1_000] {
+        let clock: VClock<u64, u64> = pairs(SEED, actors).into();
+        let other: VClock<u64, u64> = pairs(SEED + 1, actors).into();
+        let map: BTreeMap<u64, u64> = pairs(SEED, actors).into_iter().collect();
+        let other_map: BTreeMap<u64, u64> = pairs(SEED + 1, actors).into_iter().collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("vclock", actors),
+            &(clock, other),
+            |b, (clock, other)| {
+                b.iter(|| {
+                    let mut clock = clock.clone();
+                    clock.merge(other.clone());
+                    black_box(clock)
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("btreemap", actors),
+            &(map, other_map),
+            |b, (map, other)| {
+                b.iter(|| {
+                    let mut map = map.clone();
+                    for (a, c) in other.clone() {
+                        let current = map.entry(a).or_insert(c);
+                        if *current < c {
+                            *current = c;
+                        }
+                    }
+                    black_box(map)
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_serde(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_serde");
+    for actors in [10u64, 1_000] {
+        let clock: VClock<u64, u64> = pairs(SEED, actors).into();
+        let json = serde_json::to_vec(&clock.to_vec()).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("serialize", actors), &clock, |b, clock| {
+            b.iter(|| black_box(serde_json::to_vec(&clock.to_vec()).unwrap()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("deserialize", actors), &json, |b, json| {
+            b.iter(|| {
+                let pairs: Vec<(u64, u64)> = serde_json::from_slice(json).unwrap();
+                black_box(VClock::from_vec(pairs))
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_small(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vclock_small");
+    for actors in [2u64, 4, 8] {
+        group.bench_with_input(BenchmarkId::new("vclock", actors), &actors, |b, actors| {
+            b.iter(|| {
+                let mut clock = VClock::<u64, u64>::default();
+                for a in 0..*actors {
+                    clock.apply(clock.dot_incremented(&a));
+                }
+                black_box(clock)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("small", actors), &actors, |b, actors| {
+            b.iter(|| {
+                let mut clock = SmallVClock::<u64, u64, 8>::default();
+                for a in 0..*actors {
+                    clock.apply(clock.dot_incremented(&a));
+                }
+                black_box(clock)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_apply,
+    bench_clone,
+    bench_encode,
+    bench_merge,
+    bench_small,
+    bench_merge_sizes,
+    bench_apply_dot,
+    bench_baseline,
+    bench_serde
+);
+criterion_main!(benches);
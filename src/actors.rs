@@ -1,4 +1,8 @@
-use crate::Actor;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::{Actor, Zero};
 
 macro_rules! actor_impl {
     ($t:ty) => {
@@ -18,3 +22,25 @@ actor_impl!(i16);
 actor_impl!(i32);
 actor_impl!(i64);
 actor_impl!(i128);
+
+impl Zero for String {
+    fn zero() -> Self {
+        String::new()
+    }
+}
+
+impl Zero for Vec<u8> {
+    fn zero() -> Self {
+        Vec::new()
+    }
+}
+
+impl Zero for Arc<str> {
+    fn zero() -> Self {
+        Arc::from("")
+    }
+}
+
+actor_impl!(String);
+actor_impl!(Vec<u8>);
+actor_impl!(Arc<str>);
@@ -0,0 +1,306 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, VClock, CRDT};
+
+/// A growing counter whose value can never exceed a global bound.
+///
+/// The global bound is split into local quotas, one for each actor, at creation. An actor can
+/// only increment the counter while it still has local permissions, and it can transfer some of
+/// its permissions to another actor.
+pub struct BoundedCounter<A: Actor, C: Counter> {
+    quotas: BTreeMap<A, C>,
+    increments: VClock<A, C>,
+    transfers: BTreeMap<(A, A), C>,
+}
+
+/// The operations supported by a `BoundedCounter`.
+pub enum BoundedCounterOp<A: Actor, C: Counter> {
+    /// Increments the counter for an actor.
+    Increment(Dot<A, C>),
+    /// Transfers permissions between two actors.
+    Transfer {
+        /// The actor which gives away permissions.
+        from: A,
+        /// The actor which receives the permissions.
+        to: A,
+        /// The total amount of permissions transferred so far between the two actors.
+        total: C,
+    },
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter> From<Vec<(A, C)>> for BoundedCounter<A, C> {
+    fn from(quotas: Vec<(A, C)>) -> Self {
+        Self {
+            quotas: quotas.into_iter().collect(),
+            increments: Default::default(),
+            transfers: BTreeMap::new(),
+        }
+    }
+}
+
+//
+// CvRDT, CmRDT, CRDT
+//
+
+impl<A: Actor, C: Counter> CvRDT for BoundedCounter<A, C> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (actor, quota) in other.quotas {
+            self.quotas
+                .entry(actor)
+                .and_modify(|q| {
+                    if *q < quota {
+                        *q = quota
+                    }
+                })
+                .or_insert(quota);
+        }
+
+        self.increments.merge(other.increments);
+
+        for (key, total) in other.transfers {
+            self.apply_transfer(key, total);
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> CmRDT for BoundedCounter<A, C> {
+    type Op = BoundedCounterOp<A, C>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        match op {
+            BoundedCounterOp::Increment(dot) => self.increments.merge(dot.into()),
+            BoundedCounterOp::Transfer { from, to, total } => {
+                self.apply_transfer((from, to), total)
+            }
+        }
+    }
+}
+
+impl<A: Actor, C: Counter + Into<usize>> CRDT for BoundedCounter<A, C> {
+    type Output = usize;
+
+    fn value(&self) -> Self::Output {
+        self.increments
+            .iter()
+            .map(|dot| {
+                let c: usize = dot.counter.into();
+                c
+            })
+            .sum()
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter> BoundedCounter<A, C> {
+    /// Constructs a new `BoundedCounter` instance from the local quota of each actor.
+    ///
+    /// The global bound of the counter is the sum of all the local quotas.
+    pub fn new(quotas: Vec<(A, C)>) -> Self {
+        quotas.into()
+    }
+
+    /// Returns the increment operation for an actor, or `None` if the actor has no permissions left.
+    pub fn incr(&self, actor: &A) -> Option<BoundedCounterOp<A, C>> {
+        if self.permissions(actor) >= C::one() {
+            Some(BoundedCounterOp::Increment(
+                self.increments.dot_incremented(actor),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the operation which transfers permissions between two actors, or `None` if the
+    /// actor giving them away does not have enough permissions.
+    ///
+    /// Only the replica of the actor giving away permissions should generate its transfers, since
+    /// it is the only one which has observed all its increments and transfers. The operations
+    /// are expected to be delivered in causal order; until then, another replica may see an
+    /// actor give away permissions it has not received yet.
+    pub fn transfer(&self, from: &A, to: &A, amount: C) -> Option<BoundedCounterOp<A, C>> {
+        if from == to || self.permissions(from) < amount {
+            None
+        } else {
+            Some(BoundedCounterOp::Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                total: self.transferred(from, to) + amount,
+            })
+        }
+    }
+
+    /// Returns the number of increments an actor is still allowed to perform.
+    ///
+    /// Returns zero if the actor has spent more permissions than this replica knows it has,
+    /// which can happen before the transfers it received are delivered.
+    pub fn permissions(&self, actor: &A) -> C {
+        let quota = self.quotas.get(actor).copied().unwrap_or_else(C::zero);
+
+        let received = self
+            .transfers
+            .iter()
+            .filter(|((_, to), _)| to == actor)
+            .fold(C::zero(), |acc, (_, total)| acc + *total);
+
+        let given = self
+            .transfers
+            .iter()
+            .filter(|((from, _), _)| from == actor)
+            .fold(C::zero(), |acc, (_, total)| acc + *total);
+
+        let available = quota + received;
+        let spent = given + self.increments.dot_or_zero(actor).counter;
+        if spent >= available {
+            C::zero()
+        } else {
+            available - spent
+        }
+    }
+
+    /// Returns the global bound of the counter.
+    pub fn bound(&self) -> C {
+        self.quotas
+            .values()
+            .fold(C::zero(), |acc, quota| acc + *quota)
+    }
+
+    /// Determines if the value of the counter has reached the global bound.
+    pub fn at_limit(&self) -> bool {
+        let total = self
+            .increments
+            .iter()
+            .fold(C::zero(), |acc, dot| acc + dot.counter);
+
+        total >= self.bound()
+    }
+
+    fn transferred(&self, from: &A, to: &A) -> C {
+        self.transfers
+            .get(&(from.clone(), to.clone()))
+            .copied()
+            .unwrap_or_else(C::zero)
+    }
+
+    fn apply_transfer(&mut self, key: (A, A), total: C) {
+        let current = self.transfers.entry(key).or_insert_with(C::zero);
+        if *current < total {
+            *current = total;
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_new() {
+        let bc: BoundedCounter<i8, usize> = BoundedCounter::new(vec![(1, 2), (2, 3)]);
+        assert_eq!(5, bc.bound());
+        assert_eq!(0, bc.value());
+        assert_eq!(2, bc.permissions(&1));
+        assert_eq!(0, bc.permissions(&3));
+    }
+
+    #[test]
+    fn test_incr() {
+        let mut bc: BoundedCounter<i8, usize> = BoundedCounter::new(vec![(1, 2), (2, 1)]);
+        bc.apply(bc.incr(&1).unwrap());
+        bc.apply(bc.incr(&1).unwrap());
+        assert!(bc.incr(&1).is_none());
+        assert!(!bc.at_limit());
+
+        bc.apply(bc.incr(&2).unwrap());
+        assert!(bc.incr(&2).is_none());
+        assert_eq!(3, bc.value());
+        assert!(bc.at_limit());
+    }
+
+    #[test]
+    fn test_transfer() {
+        let mut bc: BoundedCounter<i8, usize> = BoundedCounter::new(vec![(1, 2), (2, 1)]);
+        assert!(bc.transfer(&2, &1, 2).is_none());
+
+        bc.apply(bc.transfer(&2, &1, 1).unwrap());
+        assert_eq!(3, bc.permissions(&1));
+        assert_eq!(0, bc.permissions(&2));
+        assert!(bc.incr(&2).is_none());
+    }
+
+    #[test]
+    fn test_permissions_out_of_order() {
+        let mut bc1: BoundedCounter<i8, usize> = BoundedCounter::new(vec![(1, 1), (2, 0)]);
+        bc1.apply(bc1.transfer(&1, &2, 1).unwrap());
+        bc1.apply(bc1.transfer(&2, &3, 1).unwrap());
+
+        let mut bc2: BoundedCounter<i8, usize> = BoundedCounter::new(vec![(1, 1), (2, 0)]);
+        bc2.apply(BoundedCounterOp::Transfer {
+            from: 2,
+            to: 3,
+            total: 1,
+        });
+        assert_eq!(0, bc2.permissions(&2));
+        assert_eq!(1, bc2.permissions(&3));
+
+        bc2.merge(bc1);
+        assert_eq!(0, bc2.permissions(&2));
+        assert_eq!(1, bc2.permissions(&3));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut bc1: BoundedCounter<i8, usize> = BoundedCounter::new(vec![(1, 2), (2, 2)]);
+        bc1.apply(bc1.incr(&1).unwrap());
+
+        let mut bc2: BoundedCounter<i8, usize> = BoundedCounter::new(vec![(1, 2), (2, 2)]);
+        bc2.apply(bc2.incr(&2).unwrap());
+        bc2.apply(bc2.transfer(&2, &1, 1).unwrap());
+
+        bc1.merge(bc2);
+        assert_eq!(2, bc1.value());
+        assert_eq!(2, bc1.permissions(&1));
+        assert_eq!(0, bc1.permissions(&2));
+    }
+    #[quickcheck]
+    fn test_merge_commutative(quotas1: Vec<(i8, u8)>, quotas2: Vec<(i8, u8)>) -> bool {
+        let counter = |quotas: &Vec<(i8, u8)>| -> BoundedCounter<i8, u64> {
+            BoundedCounter::new(quotas.iter().map(|(a, q)| (*a, *q as u64)).collect())
+        };
+
+        let mut bc1 = counter(&quotas1);
+        bc1.merge(counter(&quotas2));
+        let mut bc2 = counter(&quotas2);
+        bc2.merge(counter(&quotas1));
+
+        bc1.bound() == bc2.bound()
+            && quotas1
+                .iter()
+                .chain(quotas2.iter())
+                .all(|(a, _)| bc1.permissions(a) == bc2.permissions(a))
+    }
+}
@@ -0,0 +1,222 @@
+//! The compact binary encoding of dots and clocks.
+//!
+//! Every encoding starts with a version byte, currently `1`, followed by LEB128 variable length
+//! integers. Signed integers are zigzag encoded first, so small negative values stay small.
+//!
+//! - a dot is encoded as `version actor counter`;
+//! - a clock is encoded as `version length (actor counter)*`, with the actors in ascending order.
+//!
+//! With the `base64` feature the encoding can also be written as URL-safe base64 text, without
+//! padding, which fits in HTTP headers.
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+/// The version of the compact encoding.
+pub(crate) const COMPACT_VERSION: u8 = 1;
+
+/// The reason a compact encoding could not be decoded.
+#[derive(Debug, PartialEq)]
+pub enum DecodeError {
+    /// The encoding starts with an unknown version.
+    UnsupportedVersion(u8),
+    /// The bytes ended in the middle of a value.
+    UnexpectedEnd,
+    /// A value does not fit into its type.
+    Overflow,
+    /// A clock lists the same actor more than once.
+    DuplicateActor,
+    /// There are bytes left after the encoded value.
+    TrailingBytes,
+    /// The text is not valid URL-safe base64.
+    InvalidBase64,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::UnsupportedVersion(v) => write!(f, "unsupported version {}", v),
+            DecodeError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            DecodeError::Overflow => write!(f, "value out of range"),
+            DecodeError::DuplicateActor => write!(f, "duplicate actor"),
+            DecodeError::TrailingBytes => write!(f, "trailing bytes"),
+            DecodeError::InvalidBase64 => write!(f, "invalid base64"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// A value which can be written as a variable length integer.
+pub trait VarInt: Sized {
+    /// Appends the encoding of the value.
+    fn encode_varint(&self, out: &mut Vec<u8>);
+
+    /// Decodes a value from the front of the bytes, advancing them past it.
+    fn decode_varint(bytes: &mut &[u8]) -> Result<Self, DecodeError>;
+}
+
+fn encode_u128(mut value: u128, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn decode_u128(bytes: &mut &[u8]) -> Result<u128, DecodeError> {
+    let mut value = 0u128;
+    let mut shift = 0;
+    loop {
+        let (byte, rest) = bytes.split_first().ok_or(DecodeError::UnexpectedEnd)?;
+        *bytes = rest;
+
+        let bits = (*byte & 0x7f) as u128;
+        if shift >= 128 || (shift > 0 && bits >> (128 - shift) != 0) {
+            return Err(DecodeError::Overflow);
+        }
+        value |= bits << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+pub(crate) fn decode_version(bytes: &mut &[u8]) -> Result<(), DecodeError> {
+    let (version, rest) = bytes.split_first().ok_or(DecodeError::UnexpectedEnd)?;
+    *bytes = rest;
+    match *version {
+        COMPACT_VERSION => Ok(()),
+        v => Err(DecodeError::UnsupportedVersion(v)),
+    }
+}
+
+pub(crate) fn decode_end(bytes: &[u8]) -> Result<(), DecodeError> {
+    if bytes.is_empty() {
+        Ok(())
+    } else {
+        Err(DecodeError::TrailingBytes)
+    }
+}
+
+#[cfg(feature = "base64")]
+pub(crate) fn to_base64(bytes: &[u8]) -> alloc::string::String {
+    use base64::Engine;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+#[cfg(feature = "base64")]
+pub(crate) fn from_base64(s: &str) -> Result<Vec<u8>, DecodeError> {
+    use base64::Engine;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(s)
+        .map_err(|_| DecodeError::InvalidBase64)
+}
+
+macro_rules! varint_unsigned_impl {
+    ($t:ty) => {
+        impl VarInt for $t {
+            fn encode_varint(&self, out: &mut Vec<u8>) {
+                encode_u128(*self as u128, out);
+            }
+
+            fn decode_varint(bytes: &mut &[u8]) -> Result<Self, DecodeError> {
+                <$t>::try_from(decode_u128(bytes)?).map_err(|_| DecodeError::Overflow)
+            }
+        }
+    };
+}
+
+macro_rules! varint_signed_impl {
+    ($t:ty) => {
+        impl VarInt for $t {
+            fn encode_varint(&self, out: &mut Vec<u8>) {
+                let value = *self as i128;
+                encode_u128(((value << 1) ^ (value >> 127)) as u128, out);
+            }
+
+            fn decode_varint(bytes: &mut &[u8]) -> Result<Self, DecodeError> {
+                let value = decode_u128(bytes)?;
+                let value = ((value >> 1) as i128) ^ -((value & 1) as i128);
+                <$t>::try_from(value).map_err(|_| DecodeError::Overflow)
+            }
+        }
+    };
+}
+
+varint_unsigned_impl!(usize);
+varint_unsigned_impl!(u8);
+varint_unsigned_impl!(u16);
+varint_unsigned_impl!(u32);
+varint_unsigned_impl!(u64);
+varint_unsigned_impl!(u128);
+
+varint_signed_impl!(i8);
+varint_signed_impl!(i16);
+varint_signed_impl!(i32);
+varint_signed_impl!(i64);
+varint_signed_impl!(i128);
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    fn roundtrip<T: VarInt + PartialEq>(value: T) -> bool {
+        let mut out = Vec::new();
+        value.encode_varint(&mut out);
+
+        let mut bytes = out.as_slice();
+        T::decode_varint(&mut bytes).is_ok_and(|v| v == value) && bytes.is_empty()
+    }
+
+    #[quickcheck]
+    fn test_roundtrip_u64(value: u64) -> bool {
+        roundtrip(value)
+    }
+
+    #[quickcheck]
+    fn test_roundtrip_u128(value: u128) -> bool {
+        roundtrip(value)
+    }
+
+    #[quickcheck]
+    fn test_roundtrip_i64(value: i64) -> bool {
+        roundtrip(value)
+    }
+
+    #[quickcheck]
+    fn test_roundtrip_i128(value: i128) -> bool {
+        roundtrip(value)
+    }
+
+    #[test]
+    fn test_small_values() {
+        let mut out = Vec::new();
+        127u64.encode_varint(&mut out);
+        (-1i64).encode_varint(&mut out);
+        128u64.encode_varint(&mut out);
+        assert_eq!(vec![0x7f, 0x01, 0x80, 0x01], out);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let mut bytes: &[u8] = &[0x80];
+        assert_eq!(
+            Err(DecodeError::UnexpectedEnd),
+            u64::decode_varint(&mut bytes)
+        );
+
+        let mut bytes: &[u8] = &[0x80, 0x02];
+        assert_eq!(Err(DecodeError::Overflow), u8::decode_varint(&mut bytes));
+
+        let mut bytes: &[u8] = &[0xff; 20];
+        assert_eq!(Err(DecodeError::Overflow), u128::decode_varint(&mut bytes));
+    }
+}
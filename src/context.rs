@@ -0,0 +1,90 @@
+use crate::{Actor, Counter, CvRDT, Dot, VClock};
+
+/// The causal context, the events observed by a replica.
+pub struct CausalContext<A: Actor, C: Counter>(VClock<A, C>);
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter> Default for CausalContext<A, C> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<A: Actor, C: Counter> From<VClock<A, C>> for CausalContext<A, C> {
+    fn from(clock: VClock<A, C>) -> Self {
+        Self(clock)
+    }
+}
+
+impl<A: Actor, C: Counter> From<CausalContext<A, C>> for VClock<A, C> {
+    fn from(context: CausalContext<A, C>) -> Self {
+        context.0
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter> CausalContext<A, C> {
+    /// Constructs a new, empty `CausalContext` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines if the event identified by a dot has been observed.
+    pub fn has_seen(&self, dot: &Dot<A, C>) -> bool {
+        self.0.contains_dot(dot)
+    }
+
+    /// Records in-place the observation of the event identified by a dot.
+    pub fn advance(&mut self, dot: Dot<A, C>) {
+        self.0.merge(dot.into());
+    }
+
+    /// Returns the clock which summarizes the observed events.
+    pub fn clock(&self) -> &VClock<A, C> {
+        &self.0
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let context = CausalContext::<i8, usize>::new();
+        assert!(context.clock().is_empty());
+        assert!(!context.has_seen(&Dot::new(1, 1)));
+    }
+
+    #[test]
+    fn test_from_vclock() {
+        let clock: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let context: CausalContext<i8, usize> = clock.into();
+        assert!(context.has_seen(&Dot::new(1, 10)));
+        assert!(context.has_seen(&Dot::new(2, 5)));
+        assert!(!context.has_seen(&Dot::new(1, 11)));
+        assert!(!context.has_seen(&Dot::new(3, 1)));
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut context = CausalContext::<i8, usize>::new();
+        context.advance(Dot::new(1, 5));
+        context.advance(Dot::new(1, 3));
+        assert!(context.has_seen(&Dot::new(1, 5)));
+        assert!(!context.has_seen(&Dot::new(1, 6)));
+
+        let clock: VClock<i8, usize> = context.into();
+        assert!(clock == vec![(1, 5)].into());
+    }
+}
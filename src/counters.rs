@@ -1,3 +1,6 @@
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::CheckedCounterOps;
 use crate::Counter;
 use crate::One;
 use crate::Zero;
@@ -17,6 +20,16 @@ macro_rules! counter_impl {
         }
 
         impl Counter for $t {}
+
+        impl CheckedCounterOps for $t {
+            fn checked_add(&self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(*self, rhs)
+            }
+
+            fn checked_sub(&self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(*self, rhs)
+            }
+        }
     };
 }
 
@@ -32,3 +45,239 @@ counter_impl!(i16);
 counter_impl!(i32);
 counter_impl!(i64);
 counter_impl!(i128);
+
+/// A counter which clamps at the bounds of the underlying type instead of overflowing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating<T>(pub T);
+
+/// A counter which wraps around at the bounds of the underlying type instead of overflowing.
+///
+/// Once a counter wraps around it compares as smaller than before, so the causal order of the
+/// dots and clocks which use it no longer holds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wrapping<T>(pub T);
+
+/// A counter which panics on overflow in debug builds and clamps at the bounds of the
+/// underlying type in release builds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CheckedCounter<T>(pub T);
+
+/// The arithmetic behind `CheckedCounter`.
+trait OverflowChecked: Sized {
+    fn overflow_checked_add(self, rhs: Self) -> Self;
+    fn overflow_checked_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! overflow_checked_impl {
+    ($t:ty) => {
+        impl OverflowChecked for $t {
+            fn overflow_checked_add(self, rhs: Self) -> Self {
+                self.checked_add(rhs).unwrap_or_else(|| {
+                    if cfg!(debug_assertions) {
+                        panic!("counter overflow: {} + {} exceeds {}", self, rhs, <$t>::MAX)
+                    }
+                    self.saturating_add(rhs)
+                })
+            }
+
+            fn overflow_checked_sub(self, rhs: Self) -> Self {
+                self.checked_sub(rhs).unwrap_or_else(|| {
+                    if cfg!(debug_assertions) {
+                        panic!("counter overflow: {} - {} exceeds {}", self, rhs, <$t>::MIN)
+                    }
+                    self.saturating_sub(rhs)
+                })
+            }
+        }
+    };
+}
+
+macro_rules! wrapper_counter_impl {
+    ($w:ident, $t:ty, $add:ident, $sub:ident) => {
+        impl From<$t> for $w<$t> {
+            fn from(value: $t) -> Self {
+                Self(value)
+            }
+        }
+
+        impl core::fmt::Display for $w<$t> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl Zero for $w<$t> {
+            fn zero() -> Self {
+                Self(<$t>::zero())
+            }
+        }
+
+        impl One for $w<$t> {
+            fn one() -> Self {
+                Self(<$t>::one())
+            }
+        }
+
+        impl Add for $w<$t> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(self.0.$add(rhs.0))
+            }
+        }
+
+        impl AddAssign for $w<$t> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub for $w<$t> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(self.0.$sub(rhs.0))
+            }
+        }
+
+        impl SubAssign for $w<$t> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Counter for $w<$t> {}
+
+        impl CheckedCounterOps for $w<$t> {
+            fn checked_add(&self, rhs: Self) -> Option<Self> {
+                self.0.checked_add(rhs.0).map(Self)
+            }
+
+            fn checked_sub(&self, rhs: Self) -> Option<Self> {
+                self.0.checked_sub(rhs.0).map(Self)
+            }
+        }
+    };
+}
+
+macro_rules! wrappers_impl {
+    ($t:ty) => {
+        wrapper_counter_impl!(Saturating, $t, saturating_add, saturating_sub);
+        wrapper_counter_impl!(Wrapping, $t, wrapping_add, wrapping_sub);
+
+        overflow_checked_impl!($t);
+        wrapper_counter_impl!(
+            CheckedCounter,
+            $t,
+            overflow_checked_add,
+            overflow_checked_sub
+        );
+    };
+}
+
+wrappers_impl!(usize);
+wrappers_impl!(u8);
+wrappers_impl!(u16);
+wrappers_impl!(u32);
+wrappers_impl!(u64);
+wrappers_impl!(u128);
+
+wrappers_impl!(i8);
+wrappers_impl!(i16);
+wrappers_impl!(i32);
+wrappers_impl!(i64);
+wrappers_impl!(i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dot, VClock};
+
+    #[test]
+    fn saturating_add() {
+        let c = Saturating(u64::MAX - 1);
+        assert_eq!(Saturating(u64::MAX), c.incr());
+        assert_eq!(Saturating(u64::MAX), c.incr().incr());
+    }
+
+    #[test]
+    fn saturating_sub() {
+        let mut c = Saturating(1u64);
+        c.decr_assign();
+        c.decr_assign();
+        assert_eq!(Saturating(0), c);
+    }
+
+    #[test]
+    fn wrapping_add_sub() {
+        let c = Wrapping(u64::MAX);
+        assert_eq!(Wrapping(0), c.incr());
+        assert_eq!(Wrapping(u64::MAX), Wrapping(0u64).decr());
+    }
+
+    #[test]
+    fn checked_incr() {
+        assert_eq!(Some(6u8), 5u8.checked_incr());
+        assert_eq!(None, u8::MAX.checked_incr());
+        assert_eq!(None, 0u8.checked_decr());
+        assert_eq!(None, Saturating(u8::MAX).checked_incr());
+        assert_eq!(None, Wrapping(u8::MAX).checked_incr());
+        assert_eq!(None, CheckedCounter(u8::MAX).checked_incr());
+    }
+
+    #[test]
+    fn checked_incr_assign() {
+        let mut c = u8::MAX - 1;
+        assert!(c.checked_incr_assign());
+        assert!(!c.checked_incr_assign());
+        assert_eq!(u8::MAX, c);
+
+        let mut c = 1i8;
+        assert!(c.checked_decr_assign());
+        assert_eq!(0, c);
+    }
+
+    #[test]
+    fn checked_counter_add_sub() {
+        let c = CheckedCounter(10u64);
+        assert_eq!(CheckedCounter(11), c.incr());
+        assert_eq!(CheckedCounter(9), c.decr());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "counter overflow: 18446744073709551615 + 1")]
+    fn checked_counter_overflow() {
+        CheckedCounter(u64::MAX).incr();
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn checked_counter_saturates() {
+        assert_eq!(CheckedCounter(u64::MAX), CheckedCounter(u64::MAX).incr());
+        assert_eq!(CheckedCounter(0), CheckedCounter(0u64).decr());
+    }
+
+    #[test]
+    fn checked_counter_vclock() {
+        let mut clock: VClock<i8, CheckedCounter<u64>> = Dot::new(1, CheckedCounter(10)).into();
+        clock += Dot::new(1, CheckedCounter(20));
+        clock += Dot::new(2, CheckedCounter(5));
+        assert_eq!("<1:30,2:5>", format!("{}", clock));
+    }
+
+    #[test]
+    fn saturating_dot() {
+        let mut dot: Dot<i8, Saturating<u64>> = Dot::new(1, Saturating(u64::MAX));
+        dot.incr_assign();
+        assert_eq!("1:18446744073709551615", format!("{}", dot));
+    }
+
+    #[test]
+    fn saturating_vclock() {
+        let mut clock: VClock<i8, Saturating<u64>> = Dot::new(1, Saturating(u64::MAX)).into();
+        clock += Dot::new(1, Saturating(5));
+        assert!(clock.contains_dot(&Dot::new(1, Saturating(u64::MAX))));
+        assert_eq!("<1:18446744073709551615>", format!("{}", clock));
+    }
+}
@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+
+use crate::{Actor, Counter, Dot, VClock};
+
+/// Delivers the messages of each actor in order, buffering the ones which arrive too early.
+pub struct CausalDelivery<A: Actor, C: Counter, M> {
+    clock: VClock<A, C>,
+    pending: Vec<(Dot<A, C>, M)>,
+    ready: Vec<M>,
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, M> Default for CausalDelivery<A, C, M> {
+    fn default() -> Self {
+        Self {
+            clock: VClock::default(),
+            pending: Vec::new(),
+            ready: Vec::new(),
+        }
+    }
+}
+
+impl<A: Actor, C: Counter, M> From<VClock<A, C>> for CausalDelivery<A, C, M> {
+    fn from(clock: VClock<A, C>) -> Self {
+        Self {
+            clock,
+            pending: Vec::new(),
+            ready: Vec::new(),
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, M> CausalDelivery<A, C, M> {
+    /// Constructs a new `CausalDelivery` instance which has delivered nothing yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the clock of the delivered messages.
+    pub fn clock(&self) -> &VClock<A, C> {
+        &self.clock
+    }
+
+    /// Returns the number of buffered messages, waiting for the messages which precede them.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Receives a message identified by a dot. The message is delivered if it is the next one
+    /// of its actor and buffered if it arrived too early. Messages already delivered are dropped.
+    pub fn receive(&mut self, dot: Dot<A, C>, msg: M) {
+        let next = self.clock.dot_incremented(&dot.actor).counter;
+
+        if dot.counter == next {
+            self.deliver(dot, msg);
+            self.flush();
+        } else if dot.counter > next {
+            self.pending.push((dot, msg));
+        }
+    }
+
+    /// Drains the messages delivered so far, in delivery order.
+    pub fn ready_messages(&mut self) -> Vec<M> {
+        core::mem::take(&mut self.ready)
+    }
+
+    fn deliver(&mut self, dot: Dot<A, C>, msg: M) {
+        self.clock.advance_actor(dot.actor, dot.counter);
+        self.ready.push(msg);
+    }
+
+    fn flush(&mut self) {
+        while let Some(i) = self
+            .pending
+            .iter()
+            .position(|(dot, _)| self.clock.dot_incremented(&dot.actor) == *dot)
+        {
+            let (dot, msg) = self.pending.swap_remove(i);
+            self.deliver(dot, msg);
+        }
+
+        let clock = &self.clock;
+        self.pending.retain(|(dot, _)| !clock.contains_dot(dot));
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_order() {
+        let mut delivery = CausalDelivery::<i8, u64, &str>::new();
+        delivery.receive(Dot::new(1, 1), "a");
+        delivery.receive(Dot::new(1, 2), "b");
+
+        assert_eq!(vec!["a", "b"], delivery.ready_messages());
+        assert!(delivery.ready_messages().is_empty());
+        assert!(*delivery.clock() == vec![(1, 2)].into());
+    }
+
+    #[test]
+    fn test_out_of_order() {
+        let mut delivery = CausalDelivery::<i8, u64, &str>::new();
+        delivery.receive(Dot::new(1, 3), "c");
+        delivery.receive(Dot::new(1, 2), "b");
+        delivery.receive(Dot::new(2, 1), "x");
+
+        assert_eq!(vec!["x"], delivery.ready_messages());
+        assert_eq!(2, delivery.pending_len());
+
+        delivery.receive(Dot::new(1, 1), "a");
+        assert_eq!(vec!["a", "b", "c"], delivery.ready_messages());
+        assert_eq!(0, delivery.pending_len());
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut delivery = CausalDelivery::<i8, u64, &str>::new();
+        delivery.receive(Dot::new(1, 2), "b");
+        delivery.receive(Dot::new(1, 2), "b");
+        delivery.receive(Dot::new(1, 1), "a");
+        delivery.receive(Dot::new(1, 1), "a");
+
+        assert_eq!(vec!["a", "b"], delivery.ready_messages());
+        assert_eq!(0, delivery.pending_len());
+    }
+
+    #[test]
+    fn test_from_clock() {
+        let mut delivery: CausalDelivery<i8, u64, &str> = VClock::from(vec![(1, 5)]).into();
+        delivery.receive(Dot::new(1, 5), "old");
+        delivery.receive(Dot::new(1, 6), "new");
+
+        assert_eq!(vec!["new"], delivery.ready_messages());
+    }
+}
@@ -1,17 +1,22 @@
-use std::fmt::{Debug, Display};
-use std::hash::Hash;
-use std::ops::{Add, Sub};
-use std::ops::{AddAssign, SubAssign};
-
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::{Add, Sub};
+use core::ops::{AddAssign, SubAssign};
+
+use crate::compact::{decode_end, decode_version, COMPACT_VERSION};
 use crate::Actor;
 use crate::CausalityOrd;
 use crate::Counter;
+use crate::DecodeError;
 use crate::One;
 use crate::UpdateAssign;
+use crate::VarInt;
 use crate::Zero;
 
 /// `Dot` instance for an actor.
-#[derive(Copy)]
+#[derive(Clone, Copy)]
 pub struct Dot<A: Actor, C: Counter> {
     pub(crate) actor: A,
     pub(crate) counter: C,
@@ -36,21 +41,14 @@ impl<A: Actor, C: Counter> From<(A, C)> for Dot<A, C> {
     }
 }
 
-impl<A: Actor, C: Counter> Clone for Dot<A, C> {
-    fn clone(&self) -> Self {
-        Self {
-            actor: self.actor.clone(),
-            counter: self.counter.incr(),
-        }
-    }
-}
-
 //
 // Formatting traits
 //
 
-impl<A: Actor + std::fmt::Display, C: Counter + std::fmt::Display> std::fmt::Display for Dot<A, C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<A: Actor + core::fmt::Display, C: Counter + core::fmt::Display> core::fmt::Display
+    for Dot<A, C>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}:{}", self.actor, self.counter)
     }
 }
@@ -66,7 +64,7 @@ impl<A: Actor, C: Counter> PartialEq for Dot<A, C> {
 }
 
 impl<A: Actor, C: Counter> PartialOrd for Dot<A, C> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         if self.actor == other.actor {
             self.counter.partial_cmp(&other.counter)
         } else {
@@ -75,6 +73,18 @@ impl<A: Actor, C: Counter> PartialOrd for Dot<A, C> {
     }
 }
 
+impl<A: Actor, C: Counter> Eq for Dot<A, C> {}
+
+impl<A: Actor, C: Counter + Ord> Dot<A, C> {
+    /// Compares two dots by actor first and by counter next. This is not the causal order,
+    /// and is only meant for deterministic sorting, for example with `sort_by(Dot::total_cmp)`.
+    pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.actor
+            .cmp(&other.actor)
+            .then_with(|| self.counter.cmp(&other.counter))
+    }
+}
+
 impl<A: Actor, C: Counter> CausalityOrd for Dot<A, C> {}
 
 //
@@ -154,12 +164,106 @@ impl<A: Actor, C: Counter> UpdateAssign<C> for Dot<A, C> {
 //
 
 impl<A: Actor + Hash, C: Counter + Hash> Hash for Dot<A, C> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.actor.hash(state);
         self.counter.hash(state);
     }
 }
 
+//
+// Serialization
+//
+
+#[cfg(feature = "serde")]
+impl<A: Actor + serde::Serialize, C: Counter + serde::Serialize> serde::Serialize for Dot<A, C> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{SerializeStruct, SerializeTuple};
+
+        if serializer.is_human_readable() {
+            let mut state = serializer.serialize_struct("Dot", 2)?;
+            state.serialize_field("actor", &self.actor)?;
+            state.serialize_field("counter", &self.counter)?;
+            state.end()
+        } else {
+            let mut state = serializer.serialize_tuple(2)?;
+            state.serialize_element(&self.actor)?;
+            state.serialize_element(&self.counter)?;
+            state.end()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A, C> serde::Deserialize<'de> for Dot<A, C>
+where
+    A: Actor + serde::Deserialize<'de>,
+    C: Counter + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+
+        #[derive(serde::Deserialize)]
+        #[serde(field_identifier, rename_all = "lowercase")]
+        enum Field {
+            Actor,
+            Counter,
+        }
+
+        struct DotVisitor<A, C>(core::marker::PhantomData<(A, C)>);
+
+        impl<'de, A, C> Visitor<'de> for DotVisitor<A, C>
+        where
+            A: Actor + serde::Deserialize<'de>,
+            C: Counter + serde::Deserialize<'de>,
+        {
+            type Value = Dot<A, C>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a dot made of an actor and a counter")
+            }
+
+            fn visit_seq<V: SeqAccess<'de>>(self, mut seq: V) -> Result<Self::Value, V::Error> {
+                let actor = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(0, &self))?;
+                let counter = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(1, &self))?;
+                Ok(Dot::new(actor, counter))
+            }
+
+            fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+                let mut actor = None;
+                let mut counter = None;
+
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Actor if actor.is_some() => {
+                            return Err(Error::duplicate_field("actor"))
+                        }
+                        Field::Actor => actor = Some(map.next_value()?),
+                        Field::Counter if counter.is_some() => {
+                            return Err(Error::duplicate_field("counter"))
+                        }
+                        Field::Counter => counter = Some(map.next_value()?),
+                    }
+                }
+
+                let actor = actor.ok_or_else(|| Error::missing_field("actor"))?;
+                let counter = counter.ok_or_else(|| Error::missing_field("counter"))?;
+                Ok(Dot::new(actor, counter))
+            }
+        }
+
+        let visitor = DotVisitor(core::marker::PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_struct("Dot", &["actor", "counter"], visitor)
+        } else {
+            deserializer.deserialize_tuple(2, visitor)
+        }
+    }
+}
+
 //
 // Implementation
 //
@@ -189,7 +293,7 @@ impl<A: Actor, C: Counter> Dot<A, C> {
     /// Construsts a new `Dot` instance which has the incremented counter.
     pub fn incr(&self) -> Self {
         Self {
-            actor: self.actor,
+            actor: self.actor.clone(),
             counter: self.counter.incr(),
         }
     }
@@ -202,7 +306,7 @@ impl<A: Actor, C: Counter> Dot<A, C> {
     /// Constructs a new `Dot` instance which ahs the decremented counter.
     pub fn decr(&self) -> Self {
         Self {
-            actor: self.actor,
+            actor: self.actor.clone(),
             counter: self.counter.decr(),
         }
     }
@@ -215,10 +319,64 @@ impl<A: Actor, C: Counter> Dot<A, C> {
     /// Constructs a new `Dot` instance with the specified counter value.
     pub fn upd(&self, counter: C) -> Self {
         Self {
-            actor: self.actor,
+            actor: self.actor.clone(),
             counter,
         }
     }
+
+    /// Constructs a new `Dot` instance with the same actor and the specified counter.
+    pub fn with_counter(&self, counter: C) -> Self {
+        self.upd(counter)
+    }
+
+    /// Constructs a new `Dot` instance with the specified actor and the same counter.
+    pub fn with_actor(&self, actor: A) -> Self {
+        Self {
+            actor,
+            counter: self.counter,
+        }
+    }
+
+    /// Determines if both dots belong to the same actor, ignoring the counters.
+    pub fn same_actor(&self, other: &Self) -> bool {
+        self.actor == other.actor
+    }
+}
+
+//
+// Compact encoding
+//
+
+impl<A: Actor + VarInt, C: Counter + VarInt> Dot<A, C> {
+    /// Returns the compact binary encoding of the dot.
+    pub fn encode_compact(&self) -> Vec<u8> {
+        let mut out = vec![COMPACT_VERSION];
+        self.actor.encode_varint(&mut out);
+        self.counter.encode_varint(&mut out);
+        out
+    }
+
+    /// Decodes a dot from its compact binary encoding.
+    pub fn decode_compact(mut bytes: &[u8]) -> Result<Self, DecodeError> {
+        decode_version(&mut bytes)?;
+        let actor = A::decode_varint(&mut bytes)?;
+        let counter = C::decode_varint(&mut bytes)?;
+        decode_end(bytes)?;
+        Ok(Self::new(actor, counter))
+    }
+}
+
+#[cfg(feature = "base64")]
+impl<A: Actor + VarInt, C: Counter + VarInt> Dot<A, C> {
+    /// Returns the compact binary encoding of the dot as URL-safe base64 text.
+    pub fn to_base64(&self) -> alloc::string::String {
+        crate::compact::to_base64(&self.encode_compact())
+    }
+
+    /// Decodes a dot from the URL-safe base64 text of its compact binary encoding.
+    pub fn from_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::decode_compact(&crate::compact::from_base64(s)?)
+    }
 }
 
 //
@@ -231,27 +389,38 @@ pub struct DotRange<A: Actor, C: Counter> {
     pub(crate) range: core::ops::Range<C>,
 }
 
+impl<A: Actor, C: Counter> DotRange<A, C> {
+    /// Determines if a dot belongs to the actor and its counter falls inside the range.
+    pub fn contains(&self, dot: &Dot<A, C>) -> bool {
+        dot.actor == self.actor && self.range.contains(&dot.counter)
+    }
+
+    /// Returns an iterator over all the dots in the range.
+    pub fn iter(&self) -> impl Iterator<Item = Dot<A, C>> + '_ {
+        core::iter::successors(Some(self.range.start), |c| Some(c.incr()))
+            .take_while(|c| *c < self.range.end)
+            .map(|c| Dot::new(self.actor.clone(), c))
+    }
+}
+
+/// Formats the range as `actor:start..end`, with the end excluded.
 impl<A: Actor + Display, C: Counter + Display> Display for DotRange<A, C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}.({}..{})",
-            self.actor, self.range.start, self.range.end
-        )
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}..{}", self.actor, self.range.start, self.range.end)
     }
 }
 
 impl<A: Actor + Debug, C: Counter + Debug> Debug for DotRange<A, C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "{:?}.({:?}..{:?})",
+            "{:?}:{:?}..{:?}",
             self.actor, self.range.start, self.range.end
         )
     }
 }
 
-impl<A: Actor + Debug + Display, C: Counter + Debug + Display> std::error::Error
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> core::error::Error
     for DotRange<A, C>
 {
 }
@@ -276,7 +445,7 @@ mod tests {
             let mut shrunk_dots = Vec::new();
             if self.counter > C::zero() {
                 let dot = Self {
-                    actor: self.actor,
+                    actor: self.actor.clone(),
                     counter: self.counter.decr(),
                 };
 
@@ -488,16 +657,16 @@ mod tests {
     #[quickcheck]
     fn test_display(actor: i8, count: usize) -> bool {
         let dot: Dot<i8, usize> = (actor, count).into();
-        format!("{}", dot).len() != 0
+        !format!("{}", dot).is_empty()
     }
 
     macro_rules! test_clone {
         ($t:ty, $fnclone:ident) => {
             #[quickcheck]
             fn $fnclone(actor: i8, counter: $t) -> bool {
-                let dot: Dot<i8, $t> = (actor, counter / 2).into();
+                let dot: Dot<i8, $t> = (actor, counter).into();
                 let dot1 = dot.clone();
-                actor == dot1.actor && counter / 2 + 1 == dot1.counter
+                actor == dot1.actor && counter == dot1.counter
             }
         };
     }
@@ -508,4 +677,163 @@ mod tests {
     test_clone!(u32, u32_clone);
     test_clone!(u64, u64_clone);
     test_clone!(u128, u128_clone);
+
+    #[quickcheck]
+    fn test_with_counter(actor: i8, count1: usize, count2: usize) -> bool {
+        let dot: Dot<i8, usize> = (actor, count1).into();
+        let dot = dot.with_counter(count2);
+        actor == dot.actor && count2 == dot.counter
+    }
+
+    #[quickcheck]
+    fn test_with_actor(actor1: i8, actor2: i8, count: usize) -> bool {
+        let dot: Dot<i8, usize> = (actor1, count).into();
+        let dot = dot.with_actor(actor2);
+        actor2 == dot.actor && count == dot.counter
+    }
+
+    #[quickcheck]
+    fn test_same_actor(actor1: i8, actor2: i8, count1: usize, count2: usize) -> bool {
+        let dot1: Dot<i8, usize> = (actor1, count1).into();
+        let dot2: Dot<i8, usize> = (actor2, count2).into();
+        dot1.same_actor(&dot2) == (actor1 == actor2)
+    }
+
+    #[quickcheck]
+    fn test_total_cmp(actor1: i8, actor2: i8, count1: usize, count2: usize) -> bool {
+        let dot1 = Dot::new(actor1, count1);
+        let dot2 = Dot::new(actor2, count2);
+        dot1.total_cmp(&dot2) == (actor1, count1).cmp(&(actor2, count2))
+    }
+
+    #[test]
+    fn test_total_cmp_sort_dedup() {
+        let mut dots: Vec<Dot<i8, usize>> = vec![
+            Dot::new(2, 1),
+            Dot::new(1, 5),
+            Dot::new(1, 3),
+            Dot::new(2, 1),
+        ];
+        dots.sort_by(Dot::total_cmp);
+        dots.dedup();
+        assert!(dots == vec![Dot::new(1, 3), Dot::new(1, 5), Dot::new(2, 1)]);
+    }
+
+    #[test]
+    fn test_range_contains() {
+        let range: DotRange<i8, usize> = DotRange {
+            actor: 1,
+            range: 3..5,
+        };
+        assert!(range.contains(&Dot::new(1, 3)));
+        assert!(range.contains(&Dot::new(1, 4)));
+        assert!(!range.contains(&Dot::new(1, 5)));
+        assert!(!range.contains(&Dot::new(2, 4)));
+    }
+
+    #[test]
+    fn test_range_iter() {
+        let range: DotRange<i8, usize> = DotRange {
+            actor: 1,
+            range: 3..6,
+        };
+        let dots: Vec<Dot<i8, usize>> = range.iter().collect();
+        assert!(dots == vec![Dot::new(1, 3), Dot::new(1, 4), Dot::new(1, 5)]);
+        assert_eq!(
+            0,
+            DotRange::<i8, usize> {
+                actor: 1,
+                range: 5..5
+            }
+            .iter()
+            .count()
+        );
+    }
+
+    #[test]
+    fn test_range_display() {
+        let range: DotRange<i8, usize> = DotRange {
+            actor: 1,
+            range: 3..5,
+        };
+        assert_eq!("1:3..5", format!("{}", range));
+        assert_eq!("1:3..5", format!("{:?}", range));
+    }
+
+    #[quickcheck]
+    fn test_compact_roundtrip(actor: i8, counter: u64) -> bool {
+        let dot: Dot<i8, u64> = Dot::new(actor, counter);
+        Dot::decode_compact(&dot.encode_compact()).is_ok_and(|d| d == dot)
+    }
+
+    #[test]
+    fn test_compact_format() {
+        let dot: Dot<u8, u64> = Dot::new(3, 300);
+        assert_eq!(vec![1, 3, 0xac, 0x02], dot.encode_compact());
+
+        assert!(matches!(
+            Dot::<u8, u64>::decode_compact(&[2, 3, 1]),
+            Err(DecodeError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            Dot::<u8, u64>::decode_compact(&[1, 3, 1, 0]),
+            Err(DecodeError::TrailingBytes)
+        ));
+    }
+
+    #[cfg(feature = "base64")]
+    #[quickcheck]
+    fn test_base64_roundtrip(actor: i8, counter: u64) -> bool {
+        let dot: Dot<i8, u64> = Dot::new(actor, counter);
+        let text = dot.to_base64();
+        text.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && Dot::from_base64(&text).is_ok_and(|d| d == dot)
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_base64_invalid() {
+        assert!(matches!(
+            Dot::<i8, u64>::from_base64("not base64!"),
+            Err(DecodeError::InvalidBase64)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_tests {
+        use super::*;
+
+        #[quickcheck]
+        fn json_roundtrip(actor: i8, counter: u64) -> bool {
+            let dot: Dot<i8, u64> = Dot::new(actor, counter);
+            let json = serde_json::to_string(&dot).unwrap();
+            let back: Dot<i8, u64> = serde_json::from_str(&json).unwrap();
+            dot == back
+        }
+
+        #[test]
+        fn json_format() {
+            let dot: Dot<i8, u64> = Dot::new(1, 10);
+            let json = serde_json::to_string(&dot).unwrap();
+            assert_eq!(r#"{"actor":1,"counter":10}"#, json);
+        }
+
+        #[quickcheck]
+        fn msgpack_roundtrip(actor: i8, counter: u64) -> bool {
+            let dot: Dot<i8, u64> = Dot::new(actor, counter);
+            let bytes = rmp_serde::to_vec(&dot).unwrap();
+            let back: Dot<i8, u64> = rmp_serde::from_slice(&bytes).unwrap();
+            dot == back
+        }
+
+        #[quickcheck]
+        fn cbor_roundtrip(actor: i8, counter: u64) -> bool {
+            let dot: Dot<i8, u64> = Dot::new(actor, counter);
+            let mut bytes = Vec::new();
+            ciborium::ser::into_writer(&dot, &mut bytes).unwrap();
+            let back: Dot<i8, u64> = ciborium::de::from_reader(bytes.as_slice()).unwrap();
+            dot == back
+        }
+    }
 }
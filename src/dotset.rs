@@ -0,0 +1,209 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::Infallible;
+
+use crate::{Actor, Counter, CvRDT, Dot, VClock};
+
+/// A set of individual dots, which unlike a `VClock` does not summarize them per actor.
+pub struct DotSet<A: Actor, C: Counter> {
+    dots: BTreeMap<A, Vec<C>>,
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter> Default for DotSet<A, C> {
+    fn default() -> Self {
+        Self {
+            dots: BTreeMap::new(),
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> FromIterator<Dot<A, C>> for DotSet<A, C> {
+    fn from_iter<I: IntoIterator<Item = Dot<A, C>>>(iter: I) -> Self {
+        let mut set = Self::default();
+        for dot in iter {
+            set.insert(dot);
+        }
+        set
+    }
+}
+
+impl<A: Actor, C: Counter> From<DotSet<A, C>> for VClock<A, C> {
+    fn from(set: DotSet<A, C>) -> Self {
+        set.summarize()
+    }
+}
+
+//
+// CvRDT
+//
+
+impl<A: Actor, C: Counter> CvRDT for DotSet<A, C> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for dot in other.iter() {
+            self.insert(dot);
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter> DotSet<A, C> {
+    /// Constructs a new, empty `DotSet` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Determines if the set has no dots.
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    /// Returns the number of dots in the set.
+    pub fn len(&self) -> usize {
+        self.dots.values().map(|counters| counters.len()).sum()
+    }
+
+    /// Inserts a dot into the set. Returns `false` if the dot was already present.
+    pub fn insert(&mut self, dot: Dot<A, C>) -> bool {
+        let counters = self.dots.entry(dot.actor).or_default();
+        match Self::search(counters, &dot.counter) {
+            Ok(_) => false,
+            Err(i) => {
+                counters.insert(i, dot.counter);
+                true
+            }
+        }
+    }
+
+    /// Determines if the set contains a dot.
+    pub fn contains(&self, dot: &Dot<A, C>) -> bool {
+        self.dots
+            .get(&dot.actor)
+            .is_some_and(|counters| Self::search(counters, &dot.counter).is_ok())
+    }
+
+    /// Removes a dot from the set. Returns `true` if the dot was present.
+    pub fn remove(&mut self, dot: &Dot<A, C>) -> bool {
+        let Some(counters) = self.dots.get_mut(&dot.actor) else {
+            return false;
+        };
+
+        match Self::search(counters, &dot.counter) {
+            Ok(i) => {
+                counters.remove(i);
+                if counters.is_empty() {
+                    self.dots.remove(&dot.actor);
+                }
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the clock with the greatest counter of each actor in the set.
+    pub fn summarize(&self) -> VClock<A, C> {
+        self.dots
+            .iter()
+            .filter_map(|(a, counters)| counters.last().map(|c| Dot::new(a.clone(), *c)))
+            .collect()
+    }
+
+    /// Returns an iterator over the dots in the set, sorted by actor and counter.
+    pub fn iter(&self) -> impl Iterator<Item = Dot<A, C>> + '_ {
+        self.dots
+            .iter()
+            .flat_map(|(a, counters)| counters.iter().map(|c| Dot::new(a.clone(), *c)))
+    }
+
+    fn search(counters: &[C], counter: &C) -> Result<usize, usize> {
+        counters.binary_search_by(|c| c.partial_cmp(counter).unwrap_or(Ordering::Less))
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_default() {
+        let set = DotSet::<i8, usize>::new();
+        assert!(set.is_empty());
+        assert_eq!(0, set.len());
+        assert!(set.summarize().is_empty());
+    }
+
+    #[test]
+    fn test_insert_contains() {
+        let mut set = DotSet::<i8, usize>::new();
+        assert!(set.insert(Dot::new(1, 3)));
+        assert!(set.insert(Dot::new(1, 1)));
+        assert!(!set.insert(Dot::new(1, 3)));
+
+        assert_eq!(2, set.len());
+        assert!(set.contains(&Dot::new(1, 1)));
+        assert!(set.contains(&Dot::new(1, 3)));
+        assert!(!set.contains(&Dot::new(1, 2)));
+        assert!(!set.contains(&Dot::new(2, 1)));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set: DotSet<i8, usize> = vec![Dot::new(1, 1), Dot::new(2, 2)].into_iter().collect();
+        assert!(set.remove(&Dot::new(1, 1)));
+        assert!(!set.remove(&Dot::new(1, 1)));
+        assert!(!set.remove(&Dot::new(2, 3)));
+        assert_eq!(1, set.len());
+        assert!(!set.contains(&Dot::new(1, 1)));
+    }
+
+    #[test]
+    fn test_summarize() {
+        let set: DotSet<i8, usize> = vec![Dot::new(1, 5), Dot::new(2, 2), Dot::new(1, 3)]
+            .into_iter()
+            .collect();
+        let clock: VClock<i8, usize> = set.into();
+        assert!(clock == vec![(1, 5), (2, 2)].into());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut set1: DotSet<i8, usize> =
+            vec![Dot::new(1, 1), Dot::new(1, 3)].into_iter().collect();
+        let set2: DotSet<i8, usize> = vec![Dot::new(1, 2), Dot::new(2, 1)].into_iter().collect();
+        set1.merge(set2);
+
+        let dots: Vec<Dot<i8, usize>> = set1.iter().collect();
+        assert!(
+            dots == vec![
+                Dot::new(1, 1),
+                Dot::new(1, 2),
+                Dot::new(1, 3),
+                Dot::new(2, 1)
+            ]
+        );
+    }
+
+    #[quickcheck]
+    fn test_contains_inserted(pairs: Vec<(i8, usize)>) -> bool {
+        let set: DotSet<i8, usize> = pairs.iter().map(|(a, c)| Dot::new(*a, *c)).collect();
+        pairs.iter().all(|(a, c)| set.contains(&Dot::new(*a, *c)))
+    }
+}
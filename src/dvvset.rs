@@ -0,0 +1,256 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use crate::{Actor, CvRDT, Dot, VClock};
+
+/// A dotted version vector set, which tracks the concurrent values written by several actors.
+///
+/// For each actor the set keeps its latest counter and the values written by that actor which
+/// are still concurrent, newest first. The `i`-th value of an actor with counter `n` was written
+/// with the dot `(actor, n - i)`.
+pub struct DvvSet<A: Actor, V: Clone> {
+    entries: BTreeMap<A, (u64, Vec<V>)>,
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, V: Clone> Default for DvvSet<A, V> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+//
+// CvRDT
+//
+
+impl<A: Actor, V: Clone> CvRDT for DvvSet<A, V> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.sync(&other);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, V: Clone> DvvSet<A, V> {
+    /// Constructs a new `DvvSet` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a value written by an actor which has observed a given causal context.
+    ///
+    /// All the values covered by the context are discarded.
+    pub fn update(&mut self, context: &VClock<A, u64>, actor: A, value: V) {
+        for (a, (counter, values)) in self.entries.iter_mut() {
+            let seen = context.dot_or_zero(a).counter;
+            values.truncate(counter.saturating_sub(seen) as usize);
+        }
+
+        for dot in context.iter() {
+            let (counter, _) = self.entries.entry(dot.actor).or_default();
+            *counter = (*counter).max(dot.counter);
+        }
+
+        let (counter, values) = self.entries.entry(actor).or_default();
+        *counter += 1;
+        values.insert(0, value);
+    }
+
+    /// Synchronizes the set with another one, discarding the values obsoleted by either of them.
+    pub fn sync(&mut self, other: &DvvSet<A, V>) {
+        for (actor, (n2, l2)) in other.entries.iter() {
+            match self.entries.get_mut(actor) {
+                Some((n1, l1)) => {
+                    let floor1 = *n1 - l1.len() as u64;
+                    let floor2 = *n2 - l2.len() as u64;
+
+                    if *n1 >= *n2 {
+                        if floor1 < floor2 {
+                            l1.truncate((*n1 - floor2) as usize);
+                        }
+                    } else {
+                        let mut values = l2.clone();
+                        if floor2 < floor1 {
+                            values.truncate((*n2 - floor1) as usize);
+                        }
+                        *n1 = *n2;
+                        *l1 = values;
+                    }
+                }
+                None => {
+                    self.entries.insert(actor.clone(), (*n2, l2.clone()));
+                }
+            }
+        }
+    }
+
+    /// Returns the causal context of the set, which should be passed along with the next update.
+    pub fn context(&self) -> VClock<A, u64> {
+        self.entries
+            .iter()
+            .map(|(actor, (counter, _))| (actor.clone(), *counter))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Returns an iterator over all the concurrent values in the set.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.entries.values().flat_map(|(_, values)| values.iter())
+    }
+
+    /// Returns the value of the set if it holds exactly one, or `None` if it is empty or
+    /// holds several concurrent values.
+    pub fn value(&self) -> Option<&V> {
+        let mut values = self.values();
+        match (values.next(), values.next()) {
+            (Some(value), None) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over all the concurrent values in the set, together with the dot
+    /// they were written with.
+    pub fn all_concurrent_values(&self) -> impl Iterator<Item = (Dot<A, u64>, &V)> + '_ {
+        self.entries.iter().flat_map(|(actor, (counter, values))| {
+            values
+                .iter()
+                .enumerate()
+                .map(move |(i, value)| (Dot::new(actor.clone(), counter - i as u64), value))
+        })
+    }
+
+    /// Returns the number of concurrent values in the set, which is more than one when the
+    /// set is conflicted.
+    pub fn conflict_count(&self) -> usize {
+        self.entries.values().map(|(_, values)| values.len()).sum()
+    }
+
+    /// Returns `true` if the set holds several concurrent values.
+    pub fn is_conflicted(&self) -> bool {
+        self.values().nth(1).is_some()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted<'a>(set: &'a DvvSet<i8, &str>) -> Vec<&'a str> {
+        let mut values: Vec<_> = set.values().copied().collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn test_default() {
+        let set: DvvSet<i8, usize> = DvvSet::default();
+        assert_eq!(0, set.values().count());
+    }
+
+    #[test]
+    fn test_update_with_context() {
+        let mut set: DvvSet<i8, &str> = DvvSet::new();
+        set.update(&VClock::default(), 1, "v1");
+
+        let ctx = set.context();
+        set.update(&ctx, 1, "v2");
+        assert_eq!(vec!["v2"], sorted(&set));
+    }
+
+    #[test]
+    fn test_update_concurrent() {
+        let mut set: DvvSet<i8, &str> = DvvSet::new();
+        let ctx = set.context();
+        set.update(&ctx, 1, "v1");
+        set.update(&ctx, 1, "v2");
+        assert_eq!(vec!["v1", "v2"], sorted(&set));
+
+        let ctx = set.context();
+        set.update(&ctx, 2, "v3");
+        assert_eq!(vec!["v3"], sorted(&set));
+    }
+
+    #[test]
+    fn test_value() {
+        let mut set: DvvSet<i8, &str> = DvvSet::new();
+        assert_eq!(None, set.value());
+        assert!(!set.is_conflicted());
+
+        set.update(&VClock::default(), 1, "v1");
+        assert_eq!(Some(&"v1"), set.value());
+        assert!(!set.is_conflicted());
+
+        set.update(&VClock::default(), 2, "v2");
+        assert_eq!(None, set.value());
+        assert!(set.is_conflicted());
+
+        set.update(&set.context(), 1, "v3");
+        assert_eq!(Some(&"v3"), set.value());
+    }
+
+    #[test]
+    fn test_all_concurrent_values() {
+        let mut set: DvvSet<i8, &str> = DvvSet::new();
+        assert_eq!(0, set.conflict_count());
+
+        set.update(&VClock::default(), 1, "v1");
+        set.update(&VClock::default(), 1, "v2");
+        set.update(&VClock::default(), 2, "v3");
+        assert_eq!(3, set.conflict_count());
+
+        let values: Vec<_> = set
+            .all_concurrent_values()
+            .map(|(dot, value)| (dot.actor, dot.counter, *value))
+            .collect();
+        assert_eq!(vec![(1, 2, "v2"), (1, 1, "v1"), (2, 1, "v3")], values);
+    }
+
+    #[test]
+    fn test_sync() {
+        let mut set1: DvvSet<i8, &str> = DvvSet::new();
+        set1.update(&VClock::default(), 1, "v1");
+
+        let mut set2: DvvSet<i8, &str> = DvvSet::new();
+        set2.sync(&set1);
+        set2.update(&set1.context(), 2, "v2");
+
+        set1.update(&VClock::default(), 1, "v3");
+
+        set1.sync(&set2);
+        assert_eq!(vec!["v2", "v3"], sorted(&set1));
+
+        set2.sync(&set1);
+        assert_eq!(vec!["v2", "v3"], sorted(&set2));
+    }
+
+    #[test]
+    fn test_merge_idempotent() {
+        let mut set1: DvvSet<i8, &str> = DvvSet::new();
+        set1.update(&VClock::default(), 1, "v1");
+        set1.update(&VClock::default(), 2, "v2");
+
+        let mut set2: DvvSet<i8, &str> = DvvSet::new();
+        set2.sync(&set1);
+
+        set1.merge(set2);
+        assert_eq!(vec!["v1", "v2"], sorted(&set1));
+    }
+}
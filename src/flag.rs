@@ -0,0 +1,331 @@
+use core::convert::Infallible;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, UpdateAssign, VClock};
+
+/// An enable-wins flag, where a concurrent enable and disable resolve to enabled.
+pub struct EWFlag<A: Actor, C: Counter> {
+    state: FlagState<A, C>,
+}
+
+/// A disable-wins flag, where a concurrent enable and disable resolve to disabled.
+pub struct DWFlag<A: Actor, C: Counter> {
+    state: FlagState<A, C>,
+}
+
+/// The operations supported by the flags.
+pub enum FlagOp<A: Actor, C: Counter> {
+    /// Enables the flag.
+    Enable {
+        /// The dot which tags the operation.
+        dot: Dot<A, C>,
+        /// The causal context observed by the operation.
+        clock: VClock<A, C>,
+    },
+    /// Disables the flag.
+    Disable {
+        /// The dot which tags the operation.
+        dot: Dot<A, C>,
+        /// The causal context observed by the operation.
+        clock: VClock<A, C>,
+    },
+}
+
+/// The operation for an `EWFlag`.
+pub type EWFlagOp<A, C> = FlagOp<A, C>;
+
+/// The operation for a `DWFlag`.
+pub type DWFlagOp<A, C> = FlagOp<A, C>;
+
+//
+// FlagState - the enable and disable dots shared by both flags.
+//
+
+struct FlagState<A: Actor, C: Counter> {
+    clock: VClock<A, C>,
+    enables: VClock<A, C>,
+    disables: VClock<A, C>,
+}
+
+impl<A: Actor, C: Counter> Default for FlagState<A, C> {
+    fn default() -> Self {
+        Self {
+            clock: Default::default(),
+            enables: Default::default(),
+            disables: Default::default(),
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> FlagState<A, C> {
+    fn enable(&self, actor: &A) -> FlagOp<A, C> {
+        FlagOp::Enable {
+            dot: self.clock.dot_incremented(actor),
+            clock: self.clock.clone(),
+        }
+    }
+
+    fn disable(&self, actor: &A) -> FlagOp<A, C> {
+        FlagOp::Disable {
+            dot: self.clock.dot_incremented(actor),
+            clock: self.clock.clone(),
+        }
+    }
+
+    fn apply(&mut self, op: FlagOp<A, C>) {
+        let (dot, clock, added, removed) = match op {
+            FlagOp::Enable { dot, clock } => (dot, clock, &mut self.enables, &mut self.disables),
+            FlagOp::Disable { dot, clock } => (dot, clock, &mut self.disables, &mut self.enables),
+        };
+
+        *removed = removed
+            .iter()
+            .filter(|d| !clock.contains_greater(&d.actor, &d.counter))
+            .fold(VClock::default(), |mut acc, d| {
+                acc.upd_assign(d);
+                acc
+            });
+        added.merge(dot.clone().into());
+
+        self.clock.merge(clock);
+        self.clock.merge(dot.into());
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.enables = join(&self.enables, &self.clock, &other.enables, &other.clock);
+        self.disables = join(&self.disables, &self.clock, &other.disables, &other.clock);
+        self.clock.merge(other.clock);
+    }
+}
+
+/// Joins two sets of dots, dropping the ones which the other side has seen and removed.
+fn join<A: Actor, C: Counter>(
+    ours: &VClock<A, C>,
+    our_ctx: &VClock<A, C>,
+    theirs: &VClock<A, C>,
+    their_ctx: &VClock<A, C>,
+) -> VClock<A, C> {
+    let survives = |dot: &Dot<A, C>, dots: &VClock<A, C>, ctx: &VClock<A, C>| {
+        dots.dot_or_zero(&dot.actor) == *dot || !ctx.contains_greater(&dot.actor, &dot.counter)
+    };
+
+    let mut joined = VClock::default();
+    ours.iter()
+        .filter(|dot| survives(dot, theirs, their_ctx))
+        .chain(theirs.iter().filter(|dot| survives(dot, ours, our_ctx)))
+        .for_each(|dot| {
+            if !joined.contains_greater(&dot.actor, &dot.counter) {
+                joined.upd_assign(dot)
+            }
+        });
+    joined
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter> Default for EWFlag<A, C> {
+    fn default() -> Self {
+        Self {
+            state: Default::default(),
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> Default for DWFlag<A, C> {
+    fn default() -> Self {
+        Self {
+            state: Default::default(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter> CvRDT for EWFlag<A, C> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.state.merge(other.state);
+    }
+}
+
+impl<A: Actor, C: Counter> CmRDT for EWFlag<A, C> {
+    type Op = EWFlagOp<A, C>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        self.state.apply(op);
+    }
+}
+
+impl<A: Actor, C: Counter> CvRDT for DWFlag<A, C> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.state.merge(other.state);
+    }
+}
+
+impl<A: Actor, C: Counter> CmRDT for DWFlag<A, C> {
+    type Op = DWFlagOp<A, C>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        self.state.apply(op);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter> EWFlag<A, C> {
+    /// Constructs a new, disabled, `EWFlag` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which enables the flag on behalf of an actor.
+    pub fn enable(&self, actor: &A) -> EWFlagOp<A, C> {
+        self.state.enable(actor)
+    }
+
+    /// Returns the operation which disables the flag on behalf of an actor.
+    pub fn disable(&self, actor: &A) -> EWFlagOp<A, C> {
+        self.state.disable(actor)
+    }
+
+    /// Returns `true` if there is any enable which has not been observed by a disable.
+    pub fn value(&self) -> bool {
+        !self.state.enables.is_empty()
+    }
+}
+
+impl<A: Actor, C: Counter> DWFlag<A, C> {
+    /// Constructs a new, disabled, `DWFlag` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which enables the flag on behalf of an actor.
+    pub fn enable(&self, actor: &A) -> DWFlagOp<A, C> {
+        self.state.enable(actor)
+    }
+
+    /// Returns the operation which disables the flag on behalf of an actor.
+    pub fn disable(&self, actor: &A) -> DWFlagOp<A, C> {
+        self.state.disable(actor)
+    }
+
+    /// Returns `true` if the flag was enabled and there is no concurrent disable.
+    pub fn value(&self) -> bool {
+        self.state.disables.is_empty() && !self.state.enables.is_empty()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let flag: EWFlag<i8, usize> = EWFlag::default();
+        assert!(!flag.value());
+
+        let flag: DWFlag<i8, usize> = DWFlag::default();
+        assert!(!flag.value());
+    }
+
+    #[test]
+    fn test_ew_enable_disable() {
+        let mut flag: EWFlag<i8, usize> = EWFlag::new();
+        flag.apply(flag.enable(&1));
+        assert!(flag.value());
+
+        flag.apply(flag.disable(&2));
+        assert!(!flag.value());
+
+        flag.apply(flag.enable(&2));
+        assert!(flag.value());
+    }
+
+    #[test]
+    fn test_dw_enable_disable() {
+        let mut flag: DWFlag<i8, usize> = DWFlag::new();
+        flag.apply(flag.enable(&1));
+        assert!(flag.value());
+
+        flag.apply(flag.disable(&2));
+        assert!(!flag.value());
+
+        flag.apply(flag.enable(&2));
+        assert!(flag.value());
+    }
+
+    #[test]
+    fn test_ew_concurrent() {
+        let mut flag1: EWFlag<i8, usize> = EWFlag::new();
+        flag1.apply(flag1.enable(&1));
+
+        let mut flag2: EWFlag<i8, usize> = EWFlag::new();
+        flag2.apply(flag2.enable(&1));
+        flag2.apply(flag2.disable(&2));
+
+        let mut flag3: EWFlag<i8, usize> = EWFlag::new();
+        flag3.apply(flag3.enable(&3));
+
+        flag2.merge(flag3);
+        assert!(flag2.value());
+
+        flag1.merge(flag2);
+        assert!(flag1.value());
+    }
+
+    #[test]
+    fn test_ew_merge_disable() {
+        let mut flag1: EWFlag<i8, usize> = EWFlag::new();
+        flag1.apply(flag1.enable(&1));
+
+        let mut flag2: EWFlag<i8, usize> = EWFlag::new();
+        flag2.apply(flag1.enable(&1));
+        flag2.apply(flag2.disable(&2));
+
+        flag1.merge(flag2);
+        assert!(!flag1.value());
+    }
+
+    #[test]
+    fn test_dw_concurrent() {
+        let mut flag1: DWFlag<i8, usize> = DWFlag::new();
+        flag1.apply(flag1.enable(&1));
+
+        let mut flag2: DWFlag<i8, usize> = DWFlag::new();
+        flag2.apply(flag2.disable(&2));
+
+        flag1.merge(flag2);
+        assert!(!flag1.value());
+    }
+}
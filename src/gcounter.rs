@@ -1,17 +1,29 @@
-use std::convert::Infallible;
-use std::fmt::{Debug, Display};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::{Add, AddAssign};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 use crate::Actor;
+use crate::ActorPriorityMerge;
 use crate::CmRDT;
 use crate::Counter;
 use crate::CvRDT;
+use crate::DeltaCRDT;
 use crate::Dot;
+use crate::MergePolicy;
+use crate::MinMerge;
+use crate::Snapshot;
+use crate::SnapshotError;
 use crate::VClock;
+use crate::VClockSnapshot;
 use crate::CRDT;
 
 /// A growing counter.
 pub struct GCounter<A: Actor, C: Counter> {
-    vclock: VClock<A, C>,
+    pub(crate) vclock: VClock<A, C>,
 }
 
 //
@@ -34,6 +46,46 @@ impl<A: Actor, C: Counter> From<Vec<(A, C)>> for GCounter<A, C> {
     }
 }
 
+impl<A: Actor, C: Counter> Clone for GCounter<A, C> {
+    fn clone(&self) -> Self {
+        Self {
+            vclock: self.vclock.clone(),
+        }
+    }
+}
+
+//
+// Formatting traits
+//
+
+impl<A: Actor + Display, C: Counter + Display> Display for GCounter<A, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "GCounter{}", self.vclock)
+    }
+}
+
+//
+// Order traits
+//
+
+impl<A: Actor, C: Counter> PartialEq for GCounter<A, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vclock == other.vclock
+    }
+}
+
+impl<A: Actor, C: Counter> Eq for GCounter<A, C> {}
+
+//
+// Hashing
+//
+
+impl<A: Actor + Hash, C: Counter + Hash> Hash for GCounter<A, C> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.vclock.hash(state);
+    }
+}
+
 //
 // CvRDT, CmRDT, CRDT
 //
@@ -50,6 +102,31 @@ impl<A: Actor + Debug + Display, C: Counter + Debug + Display> CvRDT for GCounte
     }
 }
 
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> DeltaCRDT for GCounter<A, C> {
+    fn delta_since(&self, checkpoint: &Self) -> Self {
+        Self {
+            vclock: self.vclock.delta_since(&checkpoint.vclock),
+        }
+    }
+}
+
+/// The versioned state of a `GCounter`, the contribution of every actor.
+pub type GCounterSnapshot<A, C> = VClockSnapshot<A, C>;
+
+impl<A: Actor, C: Counter> Snapshot for GCounter<A, C> {
+    type SnapshotData = GCounterSnapshot<A, C>;
+
+    fn snapshot(&self) -> Self::SnapshotData {
+        self.vclock.snapshot()
+    }
+
+    fn restore(data: Self::SnapshotData) -> Result<Self, SnapshotError> {
+        Ok(Self {
+            vclock: VClock::restore(data)?,
+        })
+    }
+}
+
 impl<A: Actor + Debug + Display, C: Counter + Debug + Display> CmRDT for GCounter<A, C> {
     type Op = Dot<A, C>;
     type Validation = Infallible;
@@ -77,6 +154,28 @@ impl<A: Actor, C: Counter + Into<usize>> CRDT for GCounter<A, C> {
     }
 }
 
+//
+// Operations
+//
+
+/// Merges in-place another counter, the same as `CvRDT::merge`. The contributions of every
+/// actor are joined, not summed.
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> AddAssign for GCounter<A, C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.merge(rhs);
+    }
+}
+
+/// The merge of two counters, the same as `CvRDT::merge`.
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> Add for GCounter<A, C> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
 //
 // Implementation
 //
@@ -87,10 +186,133 @@ impl<A: Actor, C: Counter> GCounter<A, C> {
         Self::default()
     }
 
+    /// Returns `true` if no actor has incremented the counter yet.
+    pub fn is_zero(&self) -> bool {
+        self.vclock.is_zero()
+    }
+
     /// Increments in-place the counter for an actor.
     pub fn incr_assign(&mut self, actor: &A) {
         self.vclock.incr_assign(actor)
     }
+
+    /// Returns the operation which increments the counter for an actor.
+    pub fn incr_op(&self, actor: &A) -> Dot<A, C> {
+        self.vclock.dot_incremented(actor)
+    }
+
+    /// Advances in-place the counter for an actor by a given step.
+    pub fn step_up(&mut self, actor: &A, step: C) {
+        self.vclock += Dot::new(actor.clone(), step);
+    }
+
+    /// Returns the operation which advances the counter for an actor by a given step.
+    pub fn step_up_op(&self, actor: &A, step: C) -> Dot<A, C> {
+        let dot = self.vclock.dot_or_zero(actor);
+        dot.upd(dot.counter + step)
+    }
+
+    /// Resets in-place the contribution of an actor to zero, keeping the actor in the counter.
+    ///
+    /// Since the counter only grows, the reset is local only: merging with a replica which
+    /// still holds a greater value for the actor brings that value back.
+    pub fn reset_actor(&mut self, actor: &A) {
+        *self.vclock.entry(actor.clone()).or_zero() = C::zero();
+    }
+
+    /// Determines if every contribution to the counter is also known to another counter, and
+    /// the counters differ. Returns `false` for equal or concurrent counters.
+    pub fn is_dominated_by(&self, other: &Self) -> bool {
+        self.vclock.is_dominated_by(&other.vclock)
+    }
+
+    /// Determines if the counter dominates another counter, the same as
+    /// `other.is_dominated_by(self)`.
+    pub fn dominates(&self, other: &Self) -> bool {
+        other.is_dominated_by(self)
+    }
+
+    /// Removes an actor from the counter, returning its contribution if it was present.
+    ///
+    /// Like `reset_actor`, the removal is local only: merging with a replica which still
+    /// knows the actor adds it back. Removing a decommissioned actor for good requires every
+    /// replica to learn, out of band, that the actor left and to remove it as well.
+    pub fn remove_actor(&mut self, actor: &A) -> Option<C> {
+        self.vclock.remove_actor(actor)
+    }
+
+    /// Returns the contributions to the counter as dots, sorted by actor.
+    pub fn to_dot_vec(&self) -> Vec<Dot<A, C>> {
+        self.vclock.to_dot_vec()
+    }
+
+    /// Constructs a counter from dots. When an actor appears several times, the counter keeps
+    /// the greatest contribution.
+    pub fn from_dot_vec(dots: Vec<Dot<A, C>>) -> Self {
+        Self {
+            vclock: VClock::from_dot_vec(dots),
+        }
+    }
+
+    /// Replaces the contribution of an actor with the result of a function, and returns the
+    /// new contribution.
+    ///
+    /// Like `reset_actor`, lowering a contribution is local only: merging with a replica which
+    /// still holds the greater value brings that value back.
+    pub fn update_actor(&mut self, actor: &A, f: impl FnOnce(C) -> C) -> C {
+        self.vclock.update_actor(actor.clone(), f)
+    }
+
+    /// Returns the contribution of an actor to the counter.
+    pub fn value_for_actor(&self, actor: &A) -> C {
+        self.vclock.dot_or_zero(actor).counter
+    }
+}
+
+//
+// Merge policies
+//
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> MergePolicy<GCounter<A, C>>
+    for MinMerge
+{
+    fn resolve(&self, a: &GCounter<A, C>, b: &GCounter<A, C>) -> GCounter<A, C> {
+        GCounter {
+            vclock: self.resolve(&a.vclock, &b.vclock),
+        }
+    }
+}
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> MergePolicy<GCounter<A, C>>
+    for ActorPriorityMerge<A>
+{
+    fn resolve(&self, a: &GCounter<A, C>, b: &GCounter<A, C>) -> GCounter<A, C> {
+        GCounter {
+            vclock: self.resolve(&a.vclock, &b.vclock),
+        }
+    }
+}
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> GCounter<A, C> {
+    /// Merges another counter into this counter, resolving the two counters with a policy.
+    pub fn merge_with_policy<P: MergePolicy<Self>>(&mut self, other: Self, policy: &P) {
+        *self = policy.resolve(self, &other);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: Actor + Hash, C: Counter> GCounter<A, C> {
+    /// Returns the contribution of every actor as a `HashMap`.
+    pub fn to_hashmap(&self) -> HashMap<A, C> {
+        self.vclock.to_hashmap()
+    }
+
+    /// Constructs a counter from a `HashMap` with the contribution of every actor.
+    pub fn from_hashmap(map: HashMap<A, C>) -> Self {
+        Self {
+            vclock: VClock::from_hashmap(map),
+        }
+    }
 }
 
 //
@@ -100,6 +322,8 @@ impl<A: Actor, C: Counter> GCounter<A, C> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::collections::BTreeSet;
+    use quickcheck_macros::quickcheck;
 
     #[test]
     fn from_pairs() {
@@ -107,6 +331,64 @@ mod tests {
         assert_eq!(10 + 20 + 30, gc.value());
     }
 
+    #[test]
+    fn is_zero() {
+        let mut gc = GCounter::<i8, usize>::new();
+        assert!(gc.is_zero());
+
+        gc.reset_actor(&10);
+        assert!(gc.is_zero());
+
+        gc.incr_assign(&10);
+        assert!(!gc.is_zero());
+    }
+
+    #[test]
+    fn update_actor() {
+        let mut gc: GCounter<i8, usize> = vec![(1, 10)].into();
+        assert_eq!(30, gc.update_actor(&1, |c| c * 3));
+        assert_eq!(5, gc.update_actor(&2, |c| c + 5));
+        assert_eq!(35, gc.value());
+    }
+
+    #[test]
+    fn remove_actor() {
+        let mut gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        assert_eq!(Some(20), gc.remove_actor(&2));
+        assert_eq!(None, gc.remove_actor(&2));
+        assert!(gc == vec![(1, 10)].into());
+
+        gc.merge(vec![(2, 20)].into());
+        assert_eq!(20, gc.value_for_actor(&2));
+    }
+
+    #[test]
+    fn to_dot_vec() {
+        let gc: GCounter<i8, usize> = vec![(2, 20), (1, 10)].into();
+        let dots = gc.to_dot_vec();
+        assert!(dots == vec![Dot::new(1, 10), Dot::new(2, 20)]);
+
+        assert!(GCounter::from_dot_vec(dots) == gc);
+        assert!(
+            GCounter::<i8, usize>::from_dot_vec(vec![Dot::new(1, 10), Dot::new(1, 5)])
+                == vec![(1, 10)].into()
+        );
+    }
+
+    #[test]
+    fn is_dominated_by() {
+        let gc1: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let gc2: GCounter<i8, usize> = vec![(1, 10), (2, 30), (3, 5)].into();
+        let gc3: GCounter<i8, usize> = vec![(1, 20)].into();
+
+        assert!(gc1.is_dominated_by(&gc2));
+        assert!(gc2.dominates(&gc1));
+        assert!(!gc2.is_dominated_by(&gc1));
+        assert!(!gc1.is_dominated_by(&gc1));
+        assert!(!gc1.is_dominated_by(&gc3));
+        assert!(!gc3.is_dominated_by(&gc1));
+    }
+
     #[test]
     fn incr_assign() {
         let mut gc = GCounter::<i8, usize>::new();
@@ -135,6 +417,22 @@ mod tests {
         assert_eq!(1 + 2 + 3, gc1.value());
     }
 
+    #[test]
+    fn add() {
+        let gc1: GCounter<i8, usize> = vec![(10, 1), (20, 2)].into();
+        let gc2: GCounter<i8, usize> = vec![(20, 1), (30, 3)].into();
+
+        let mut merged = gc1.clone();
+        merged.merge(gc2.clone());
+
+        let mut accumulated = gc1.clone();
+        accumulated += gc2.clone();
+
+        assert!(accumulated == merged);
+        assert!(gc1 + gc2 == merged);
+        assert_eq!(1 + 2 + 3, merged.value());
+    }
+
     #[test]
     fn apply() {
         let mut gc1 = GCounter::<i8, usize>::new();
@@ -146,4 +444,188 @@ mod tests {
 
         assert_eq!(3 + 2, gc1.value());
     }
+
+    #[test]
+    fn step_up() {
+        let mut gc: GCounter<i8, usize> = vec![(1, 10)].into();
+        gc.step_up(&1, 5);
+        gc.step_up(&2, 7);
+
+        assert_eq!(15, gc.value_for_actor(&1));
+        assert_eq!(7, gc.value_for_actor(&2));
+    }
+
+    #[quickcheck]
+    fn step_up_op(pairs: Vec<(i8, u16)>, actor: i8, step: u16) -> bool {
+        let pairs: Vec<(i8, usize)> = pairs.into_iter().map(|(a, c)| (a, c as usize)).collect();
+        let mut gc1: GCounter<i8, usize> = pairs.clone().into();
+        let mut gc2: GCounter<i8, usize> = pairs.into();
+
+        gc1.step_up(&actor, step as usize);
+        gc2.apply(gc2.step_up_op(&actor, step as usize));
+        gc1.value() == gc2.value() && gc1.value_for_actor(&actor) == gc2.value_for_actor(&actor)
+    }
+
+    #[test]
+    fn reset_actor() {
+        let mut gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        gc.reset_actor(&1);
+
+        assert_eq!(0, gc.value_for_actor(&1));
+        assert_eq!(20, gc.value());
+    }
+
+    #[test]
+    fn reset_actor_merge() {
+        let mut gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        gc.reset_actor(&1);
+        gc.merge(vec![(1, 10)].into());
+
+        assert_eq!(10, gc.value_for_actor(&1));
+    }
+
+    #[test]
+    fn display() {
+        let gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        assert_eq!("GCounter<1:10,2:20>", gc.to_string());
+    }
+
+    #[test]
+    fn string_actors() {
+        let mut gc1: GCounter<String, usize> = GCounter::new();
+        gc1.apply(gc1.incr_op(&"alice".to_string()));
+
+        let mut gc2: GCounter<String, usize> = GCounter::new();
+        gc2.apply(gc2.incr_op(&"bob".to_string()));
+        gc2.apply(gc2.incr_op(&"bob".to_string()));
+
+        gc1.merge(gc2);
+        assert_eq!(3, gc1.value());
+        assert_eq!(2, gc1.value_for_actor(&"bob".to_string()));
+    }
+
+    #[test]
+    fn value_for_actor() {
+        let gc: GCounter<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        assert_eq!(20, gc.value_for_actor(&2));
+        assert_eq!(0, gc.value_for_actor(&4));
+    }
+
+    #[quickcheck]
+    fn value_for_actor_sum(pairs: Vec<(i8, u8)>) -> bool {
+        let actors: BTreeSet<i8> = pairs.iter().map(|(a, _)| *a).collect();
+        let gc: GCounter<i8, u8> = pairs.into();
+
+        let sum: usize = actors.iter().map(|a| gc.value_for_actor(a) as usize).sum();
+        sum == gc.value()
+    }
+
+    #[test]
+    fn delta_since() {
+        let mut checkpoint: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let gc: GCounter<i8, usize> = vec![(1, 10), (2, 25), (3, 5)].into();
+
+        let delta = gc.delta_since(&checkpoint);
+        assert!(delta.vclock == vec![(2, 25), (3, 5)].into());
+
+        checkpoint.apply_delta(delta);
+        assert!(checkpoint.vclock == gc.vclock);
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        let gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        assert_eq!(vec![(1, 10), (2, 20)], gc.snapshot().dots);
+
+        let restored = GCounter::restore(gc.snapshot()).unwrap();
+        assert!(restored.vclock == gc.vclock);
+
+        assert!(GCounter::<i8, usize>::restore(vec![(1, 10), (1, 20)].into()).is_err());
+    }
+
+    #[test]
+    fn incr_op() {
+        let mut gc: GCounter<i8, usize> = vec![(1, 10)].into();
+        let op = gc.incr_op(&1);
+        assert_eq!(10, gc.value_for_actor(&1));
+
+        gc.apply(op);
+        gc.apply(gc.incr_op(&2));
+        assert_eq!(11, gc.value_for_actor(&1));
+        assert_eq!(1, gc.value_for_actor(&2));
+    }
+
+    #[test]
+    fn clone() {
+        let gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let mut cloned = gc.clone();
+        assert!(cloned == gc);
+
+        cloned.incr_assign(&1);
+        assert_eq!(10, gc.value_for_actor(&1));
+        assert_eq!(11, cloned.value_for_actor(&1));
+    }
+
+    #[test]
+    fn hash_map() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(GCounter::<i8, usize>::from(vec![(1, 10), (2, 20)]), "a");
+        map.insert(GCounter::<i8, usize>::from(vec![(1, 10)]), "b");
+
+        assert_eq!(Some(&"a"), map.get(&vec![(2, 20), (1, 10)].into()));
+        assert_eq!(Some(&"b"), map.get(&vec![(1, 10)].into()));
+        assert_eq!(None, map.get(&vec![(1, 20)].into()));
+    }
+
+    #[test]
+    fn merge_with_policy() {
+        let gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let other: GCounter<i8, usize> = vec![(1, 15), (2, 25)].into();
+
+        let mut min = gc.clone();
+        min.merge_with_policy(other.clone(), &MinMerge);
+        assert_eq!(30, min.value());
+
+        let mut trusted = gc.clone();
+        trusted.merge_with_policy(other, &ActorPriorityMerge::new([2]));
+        assert_eq!(35, trusted.value());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hashmap_roundtrip() {
+        let gc: GCounter<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let map = gc.to_hashmap();
+        assert_eq!(Some(&20), map.get(&2));
+
+        assert!(GCounter::from_hashmap(map) == gc);
+    }
+
+    #[cfg(feature = "testing")]
+    mod law_tests {
+        use super::*;
+        use crate::testing::*;
+
+        type Pairs = Vec<(i8, usize)>;
+
+        fn make(pairs: &Pairs) -> GCounter<i8, usize> {
+            pairs.clone().into()
+        }
+
+        fn make_op(pair: &(i8, usize)) -> Dot<i8, usize> {
+            Dot::new(pair.0, pair.1)
+        }
+
+        #[test]
+        fn cvrdt_laws() {
+            assert_cvrdt_commutativity(make);
+            assert_cvrdt_associativity(make);
+            assert_cvrdt_idempotence(make);
+        }
+
+        #[test]
+        fn cmrdt_laws() {
+            assert_cmrdt_idempotence(make, make_op);
+        }
+    }
 }
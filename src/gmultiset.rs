@@ -0,0 +1,197 @@
+use alloc::collections::BTreeMap;
+use core::convert::Infallible;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, VClock};
+
+/// A grow-only multiset, where every actor counts the copies of an element it inserted.
+pub struct GMultiset<A: Actor, C: Counter, E: Ord> {
+    counts: BTreeMap<E, VClock<A, C>>,
+}
+
+/// The operation for a `GMultiset`, the dot which counts the copies of the inserted element.
+pub type GMultisetOp<A, C, E> = (Dot<A, C>, E);
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, E: Ord> Default for GMultiset<A, C, E> {
+    fn default() -> Self {
+        Self {
+            counts: BTreeMap::new(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter, E: Ord> CvRDT for GMultiset<A, C, E> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (element, clock) in other.counts {
+            self.counts.entry(element).or_default().merge(clock);
+        }
+    }
+}
+
+impl<A: Actor, C: Counter, E: Ord> CmRDT for GMultiset<A, C, E> {
+    type Op = GMultisetOp<A, C, E>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        let (dot, element) = op;
+        self.counts.entry(element).or_default().merge(dot.into());
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, E: Ord> GMultiset<A, C, E> {
+    /// Constructs a new `GMultiset` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which inserts a copy of an element on behalf of an actor.
+    pub fn insert(&self, actor: &A, element: E) -> GMultisetOp<A, C, E> {
+        let dot = match self.counts.get(&element) {
+            Some(clock) => clock.dot_incremented(actor),
+            None => Dot::new(actor.clone(), C::one()),
+        };
+        (dot, element)
+    }
+
+    /// Determines if the multiset contains at least one copy of a given element.
+    pub fn contains(&self, element: &E) -> bool {
+        self.counts
+            .get(element)
+            .is_some_and(|clock| !clock.is_zero())
+    }
+
+    /// Returns `true` if the multiset contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.counts.values().all(|clock| clock.is_zero())
+    }
+
+    /// Returns the number of copies of a given element.
+    pub fn count(&self, element: &E) -> C {
+        self.counts.get(element).map_or_else(C::zero, Self::sum)
+    }
+
+    /// Returns the total number of copies of all the elements.
+    pub fn total(&self) -> C {
+        self.counts
+            .values()
+            .fold(C::zero(), |acc, clock| acc + Self::sum(clock))
+    }
+
+    /// Returns an iterator over the elements and their number of copies, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = (&E, C)> + '_ {
+        self.counts
+            .iter()
+            .map(|(element, clock)| (element, Self::sum(clock)))
+    }
+
+    fn sum(clock: &VClock<A, C>) -> C {
+        clock.iter().fold(C::zero(), |acc, dot| acc + dot.counter)
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_default() {
+        let set: GMultiset<i8, usize, &str> = GMultiset::default();
+        assert!(set.is_empty());
+        assert_eq!(0, set.total());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set: GMultiset<i8, usize, &str> = GMultiset::new();
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.insert(&2, "a"));
+        set.apply(set.insert(&2, "b"));
+
+        assert_eq!(3, set.count(&"a"));
+        assert_eq!(1, set.count(&"b"));
+        assert_eq!(0, set.count(&"c"));
+        assert_eq!(4, set.total());
+        assert!(set.contains(&"b"));
+        assert!(!set.contains(&"c"));
+    }
+
+    #[test]
+    fn test_apply_idempotent() {
+        let mut set: GMultiset<i8, usize, &str> = GMultiset::new();
+        let op = set.insert(&1, "a");
+        set.apply(op);
+        set.apply(op);
+
+        assert_eq!(1, set.count(&"a"));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut set1: GMultiset<i8, usize, &str> = GMultiset::new();
+        set1.apply(set1.insert(&1, "a"));
+        set1.apply(set1.insert(&1, "a"));
+
+        let mut set2: GMultiset<i8, usize, &str> = GMultiset::new();
+        set2.apply(set2.insert(&1, "a"));
+        set2.apply(set2.insert(&2, "a"));
+        set2.apply(set2.insert(&2, "b"));
+
+        set1.merge(set2);
+        assert_eq!(3, set1.count(&"a"));
+        assert_eq!(1, set1.count(&"b"));
+        assert_eq!(vec![(&"a", 3), (&"b", 1)], set1.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_u64_counter() {
+        let mut set: GMultiset<i8, u64, &str> = GMultiset::new();
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.insert(&2, "a"));
+        set.apply(set.insert(&2, "b"));
+
+        assert_eq!(2u64, set.count(&"a"));
+        assert_eq!(3u64, set.total());
+        assert_eq!(
+            vec![(&"a", 2u64), (&"b", 1)],
+            set.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[quickcheck]
+    fn test_total(inserts: Vec<(i8, u8)>) -> bool {
+        let mut set: GMultiset<i8, usize, u8> = GMultiset::new();
+        for (actor, element) in inserts.iter() {
+            set.apply(set.insert(actor, *element));
+        }
+
+        set.total() == inserts.len()
+            && set.iter().map(|(_, count)| count).sum::<usize>() == set.total()
+    }
+}
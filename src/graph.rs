@@ -0,0 +1,130 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Actor, Counter, VClock};
+
+/// A set of labeled events and the happens-before relation between them, given by the vclock
+/// recorded for every event. Useful to inspect causal histories in tests and examples.
+pub struct CausalGraph<A: Actor, C: Counter> {
+    events: Vec<(String, VClock<A, C>)>,
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter> Default for CausalGraph<A, C> {
+    fn default() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter> CausalGraph<A, C> {
+    /// Constructs a new, empty `CausalGraph` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the clock of an event, replacing the clock of an event with the same label.
+    pub fn add_event(&mut self, label: String, clock: VClock<A, C>) {
+        match self.events.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, c)) => *c = clock,
+            None => self.events.push((label, clock)),
+        }
+    }
+
+    /// Returns the clock recorded for an event.
+    pub fn clock(&self, label: &str) -> Option<&VClock<A, C>> {
+        self.events
+            .iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, clock)| clock)
+    }
+
+    /// Returns the number of events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no event has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Determines if an event causally precedes another one. Returns `false` if either event
+    /// is unknown.
+    pub fn happens_before(&self, a: &str, b: &str) -> bool {
+        match (self.clock(a), self.clock(b)) {
+            (Some(a), Some(b)) => a.is_dominated_by(b),
+            _ => false,
+        }
+    }
+
+    /// Returns the pairs of concurrent events, in the order the events were recorded.
+    pub fn concurrent_events(&self) -> Vec<(&str, &str)> {
+        let mut pairs = Vec::new();
+        for (i, (a, ca)) in self.events.iter().enumerate() {
+            for (b, cb) in self.events[i + 1..].iter() {
+                if ca != cb && !ca.is_dominated_by(cb) && !cb.is_dominated_by(ca) {
+                    pairs.push((a.as_str(), b.as_str()));
+                }
+            }
+        }
+        pairs
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> CausalGraph<i8, usize> {
+        let mut graph = CausalGraph::new();
+        graph.add_event("a".into(), vec![(1, 1)].into());
+        graph.add_event("b".into(), vec![(1, 2)].into());
+        graph.add_event("c".into(), vec![(1, 1), (2, 1)].into());
+        graph.add_event("d".into(), vec![(1, 2), (2, 1)].into());
+        graph
+    }
+
+    #[test]
+    fn test_happens_before() {
+        let graph = graph();
+        assert_eq!(4, graph.len());
+
+        assert!(graph.happens_before("a", "b"));
+        assert!(graph.happens_before("a", "c"));
+        assert!(graph.happens_before("c", "d"));
+        assert!(!graph.happens_before("b", "a"));
+        assert!(!graph.happens_before("b", "c"));
+        assert!(!graph.happens_before("a", "a"));
+        assert!(!graph.happens_before("a", "x"));
+    }
+
+    #[test]
+    fn test_concurrent_events() {
+        let graph = graph();
+        assert_eq!(vec![("b", "c")], graph.concurrent_events());
+    }
+
+    #[test]
+    fn test_add_event_replaces() {
+        let mut graph = graph();
+        graph.add_event("b".into(), vec![(2, 5)].into());
+
+        assert_eq!(4, graph.len());
+        assert!(graph.clock("b") == Some(&vec![(2, 5)].into()));
+        assert_eq!(
+            vec![("a", "b"), ("b", "c"), ("b", "d")],
+            graph.concurrent_events()
+        );
+    }
+}
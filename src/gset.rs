@@ -0,0 +1,149 @@
+use alloc::collections::{btree_set, BTreeSet};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+
+use crate::{CmRDT, CvRDT};
+
+/// A grow-only set.
+pub struct GSet<E: Ord> {
+    elements: BTreeSet<E>,
+}
+
+/// The operation for a `GSet`, which is the inserted element itself.
+pub type GSetOp<E> = E;
+
+//
+// Constructors
+//
+
+impl<E: Ord> Default for GSet<E> {
+    fn default() -> Self {
+        Self {
+            elements: BTreeSet::new(),
+        }
+    }
+}
+
+impl<E: Ord> From<Vec<E>> for GSet<E> {
+    fn from(elements: Vec<E>) -> Self {
+        Self {
+            elements: elements.into_iter().collect(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<E: Ord> CvRDT for GSet<E> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.elements.extend(other.elements);
+    }
+}
+
+impl<E: Ord> CmRDT for GSet<E> {
+    type Op = GSetOp<E>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        self.elements.insert(op);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<E: Ord> GSet<E> {
+    /// Constructs a new `GSet` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which inserts an element into the set.
+    pub fn insert(&self, element: E) -> GSetOp<E> {
+        element
+    }
+
+    /// Determines if the set contains a given element.
+    pub fn contains(&self, element: &E) -> bool {
+        self.elements.contains(element)
+    }
+
+    /// Returns `true` if the set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns an iterator over the elements of the set, in ascending order.
+    pub fn iter(&self) -> btree_set::Iter<'_, E> {
+        self.elements.iter()
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_default() {
+        let set: GSet<i8> = GSet::default();
+        assert!(set.is_empty());
+    }
+
+    #[quickcheck]
+    fn test_insert(element: i8) -> bool {
+        let mut set = GSet::new();
+        set.apply(set.insert(element));
+        set.contains(&element) && set.len() == 1
+    }
+
+    #[quickcheck]
+    fn test_apply_idempotent(element: i8) -> bool {
+        let mut set = GSet::new();
+        set.apply(element);
+        set.apply(element);
+        set.len() == 1
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut set1: GSet<i8> = vec![1, 2, 3].into();
+        let set2: GSet<i8> = vec![3, 4].into();
+
+        set1.merge(set2);
+        assert_eq!(vec![1, 2, 3, 4], set1.iter().copied().collect::<Vec<_>>());
+    }
+
+    #[quickcheck]
+    fn test_merge_commutative(xs: Vec<i8>, ys: Vec<i8>) -> bool {
+        let mut set1: GSet<i8> = xs.clone().into();
+        set1.merge(ys.clone().into());
+
+        let mut set2: GSet<i8> = ys.into();
+        set2.merge(xs.into());
+
+        set1.iter().eq(set2.iter())
+    }
+}
@@ -0,0 +1,231 @@
+use core::convert::Infallible;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, VClock};
+
+/// A vclock backed by a `HashMap`, for actors which are `Hash + Eq` but not `Ord`.
+///
+/// Unlike `VClock`, the iteration order over the actors is not specified. Since such actors
+/// cannot form a `Dot`, the dots of this clock are represented as `(actor, counter)` pairs.
+pub struct HashVClock<A: Hash + Eq, C: Counter> {
+    dots: HashMap<A, C>,
+}
+
+//
+// Constructors
+//
+
+impl<A: Hash + Eq, C: Counter> Default for HashVClock<A, C> {
+    fn default() -> Self {
+        Self {
+            dots: HashMap::new(),
+        }
+    }
+}
+
+impl<A: Hash + Eq, C: Counter> From<Vec<(A, C)>> for HashVClock<A, C> {
+    fn from(pairs: Vec<(A, C)>) -> Self {
+        Self {
+            dots: pairs.into_iter().collect(),
+        }
+    }
+}
+
+impl<A: Actor + Hash, C: Counter> From<VClock<A, C>> for HashVClock<A, C> {
+    fn from(clock: VClock<A, C>) -> Self {
+        Self {
+            dots: clock.into_actor_counters().collect(),
+        }
+    }
+}
+
+//
+// Order traits
+//
+
+/// As for `VClock`, a missing actor counts as zero, so the zero counters do not affect equality.
+impl<A: Hash + Eq, C: Counter> PartialEq for HashVClock<A, C> {
+    fn eq(&self, other: &Self) -> bool {
+        let covered = |l: &Self, r: &Self| l.dots.iter().all(|(a, c)| r.counter_or_zero(a) == *c);
+        covered(self, other) && covered(other, self)
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Hash + Eq, C: Counter> CvRDT for HashVClock<A, C> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for dot in other.dots {
+            self.apply_dot(dot);
+        }
+    }
+}
+
+impl<A: Hash + Eq, C: Counter> CmRDT for HashVClock<A, C> {
+    type Op = (A, C);
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        self.apply_dot(op);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Hash + Eq, C: Counter> HashVClock<A, C> {
+    /// Constructs a new `HashVClock` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` is the clock contains dots.
+    pub fn is_empty(&self) -> bool {
+        self.dots.is_empty()
+    }
+
+    /// Returns the number of dots in the clock.
+    pub fn len(&self) -> usize {
+        self.dots.len()
+    }
+
+    /// Returns the counter stored for a given actor, or zero if the actor is not known.
+    pub fn counter_or_zero(&self, actor: &A) -> C {
+        self.dots.get(actor).copied().unwrap_or_else(C::zero)
+    }
+
+    /// Determines if the dot made of a given actor and counter has been observed by the clock.
+    pub fn contains_dot(&self, actor: &A, counter: &C) -> bool {
+        self.counter_or_zero(actor) >= *counter
+    }
+
+    /// Returns an iterator over the actor and counter pairs of the clock, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&A, &C)> + '_ {
+        self.dots.iter()
+    }
+
+    /// Consumes the clock and returns an iterator over its actor and counter pairs.
+    pub fn into_actor_counters(self) -> impl Iterator<Item = (A, C)> {
+        self.dots.into_iter()
+    }
+
+    /// Increments in-place the counter for a specified actor.
+    pub fn incr_assign(&mut self, actor: A) {
+        self.dots.entry(actor).or_insert_with(C::zero).incr_assign();
+    }
+
+    fn apply_dot(&mut self, (actor, counter): (A, C)) {
+        let current = self.dots.entry(actor).or_insert(counter);
+        if *current < counter {
+            *current = counter;
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_default() {
+        let clock: HashVClock<String, usize> = HashVClock::default();
+        assert!(clock.is_empty());
+    }
+
+    #[test]
+    fn test_incr_assign() {
+        let mut clock: HashVClock<String, usize> = HashVClock::new();
+        clock.incr_assign("a".to_string());
+        clock.incr_assign("a".to_string());
+        clock.incr_assign("b".to_string());
+
+        assert_eq!(2, clock.counter_or_zero(&"a".to_string()));
+        assert_eq!(1, clock.counter_or_zero(&"b".to_string()));
+        assert_eq!(0, clock.counter_or_zero(&"c".to_string()));
+    }
+
+    #[test]
+    fn test_contains_dot() {
+        let clock: HashVClock<String, usize> = vec![("a".to_string(), 10)].into();
+        assert!(clock.contains_dot(&"a".to_string(), &10));
+        assert!(!clock.contains_dot(&"a".to_string(), &11));
+        assert!(clock.contains_dot(&"b".to_string(), &0));
+    }
+
+    #[test]
+    fn test_eq_zero_entries() {
+        let clock1: HashVClock<String, usize> = vec![("a".to_string(), 0)].into();
+        let clock2: HashVClock<String, usize> = HashVClock::new();
+        assert!(clock1 == clock2);
+        assert!(clock2 == clock1);
+    }
+
+    #[quickcheck]
+    fn test_eq_matches_vclock(pairs1: Vec<(i8, u8)>, pairs2: Vec<(i8, u8)>) -> bool {
+        let clock1: VClock<i8, u8> = pairs1.into();
+        let clock2: VClock<i8, u8> = pairs2.into();
+        let expected = clock1 == clock2;
+        (HashVClock::from(clock1) == HashVClock::from(clock2)) == expected
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut clock1: HashVClock<String, usize> =
+            vec![("a".to_string(), 10), ("b".to_string(), 20)].into();
+        let clock2: HashVClock<String, usize> = vec![
+            ("a".to_string(), 100),
+            ("b".to_string(), 10),
+            ("c".to_string(), 30),
+        ]
+        .into();
+
+        clock1.merge(clock2);
+
+        let expected: HashVClock<String, usize> = vec![
+            ("a".to_string(), 100),
+            ("b".to_string(), 20),
+            ("c".to_string(), 30),
+        ]
+        .into();
+        assert!(clock1 == expected);
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut clock: HashVClock<String, usize> = vec![("a".to_string(), 10)].into();
+        clock.apply(("a".to_string(), 5));
+        clock.apply(("b".to_string(), 5));
+
+        assert_eq!(10, clock.counter_or_zero(&"a".to_string()));
+        assert_eq!(5, clock.counter_or_zero(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_from_vclock() {
+        let vclock: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let clock: HashVClock<i8, usize> = vclock.into();
+
+        let mut pairs: Vec<_> = clock.into_actor_counters().collect();
+        pairs.sort();
+        assert_eq!(vec![(1, 10), (2, 20)], pairs);
+    }
+}
@@ -0,0 +1,192 @@
+use core::fmt::Display;
+
+use crate::{Actor, CausalityOrd};
+
+/// A timestamp issued by a `HybridLogicalClock`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HlcTimestamp<A: Actor> {
+    /// The physical time, in milliseconds since the epoch.
+    pub physical: u64,
+    /// The logical counter, which orders the events sharing the same physical time.
+    pub logical: u64,
+    /// The actor which issued the timestamp.
+    pub actor: A,
+}
+
+/// A hybrid logical clock, which combines the physical time with a logical counter.
+pub struct HybridLogicalClock<A: Actor> {
+    physical: u64,
+    logical: u64,
+    actor: A,
+}
+
+//
+// Formatting traits
+//
+
+impl<A: Actor + Display> Display for HlcTimestamp<A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}@{}", self.physical, self.logical, self.actor)
+    }
+}
+
+//
+// Order traits
+//
+
+/// Timestamps are ordered by physical time and then by logical counter. Timestamps with the
+/// same physical time and counter issued by different actors are concurrent.
+impl<A: Actor> PartialOrd for HlcTimestamp<A> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match (self.physical, self.logical).cmp(&(other.physical, other.logical)) {
+            core::cmp::Ordering::Equal if self.actor != other.actor => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<A: Actor> CausalityOrd for HlcTimestamp<A> {}
+
+//
+// Implementation
+//
+
+impl<A: Actor> HybridLogicalClock<A> {
+    /// Constructs a new `HybridLogicalClock` instance for an actor.
+    pub fn new(actor: A) -> Self {
+        Self {
+            physical: 0,
+            logical: 0,
+            actor,
+        }
+    }
+
+    /// Returns the current timestamp of the clock.
+    pub fn timestamp(&self) -> HlcTimestamp<A> {
+        HlcTimestamp {
+            physical: self.physical,
+            logical: self.logical,
+            actor: self.actor.clone(),
+        }
+    }
+
+    /// Advances the clock for a local or send event, given the current wall time in milliseconds.
+    pub fn now(&mut self, wall_ms: u64) -> HlcTimestamp<A> {
+        if wall_ms > self.physical {
+            self.physical = wall_ms;
+            self.logical = 0;
+        } else {
+            self.logical += 1;
+        }
+        self.timestamp()
+    }
+
+    /// Advances the clock past the timestamp of a received message, given the current wall time.
+    pub fn receive(&mut self, msg_ts: HlcTimestamp<A>, wall_ms: u64) {
+        let physical = self.physical.max(msg_ts.physical).max(wall_ms);
+
+        self.logical = if physical == self.physical && physical == msg_ts.physical {
+            self.logical.max(msg_ts.logical) + 1
+        } else if physical == self.physical {
+            self.logical + 1
+        } else if physical == msg_ts.physical {
+            msg_ts.logical + 1
+        } else {
+            0
+        };
+        self.physical = physical;
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Causality;
+
+    fn ts(physical: u64, logical: u64, actor: i8) -> HlcTimestamp<i8> {
+        HlcTimestamp {
+            physical,
+            logical,
+            actor,
+        }
+    }
+
+    #[test]
+    fn test_now() {
+        let mut clock = HybridLogicalClock::new(1);
+        assert_eq!(ts(100, 0, 1), clock.now(100));
+        assert_eq!(ts(100, 1, 1), clock.now(100));
+        assert_eq!(ts(100, 2, 1), clock.now(90));
+        assert_eq!(ts(120, 0, 1), clock.now(120));
+    }
+
+    #[test]
+    fn test_receive_ahead() {
+        let mut clock = HybridLogicalClock::new(1);
+        clock.now(100);
+
+        clock.receive(ts(150, 3, 2), 110);
+        assert_eq!(ts(150, 4, 1), clock.timestamp());
+    }
+
+    #[test]
+    fn test_receive_same_physical() {
+        let mut clock = HybridLogicalClock::new(1);
+        clock.now(100);
+        clock.now(100);
+
+        clock.receive(ts(100, 5, 2), 90);
+        assert_eq!(ts(100, 6, 1), clock.timestamp());
+    }
+
+    #[test]
+    fn test_receive_behind() {
+        let mut clock = HybridLogicalClock::new(1);
+        clock.now(100);
+
+        clock.receive(ts(50, 7, 2), 90);
+        assert_eq!(ts(100, 1, 1), clock.timestamp());
+
+        clock.receive(ts(50, 7, 2), 200);
+        assert_eq!(ts(200, 0, 1), clock.timestamp());
+    }
+
+    #[test]
+    fn test_receive_is_after_message() {
+        let mut clock1 = HybridLogicalClock::new(1);
+        let mut clock2 = HybridLogicalClock::new(2);
+
+        let sent = clock1.now(100);
+        clock2.receive(sent, 80);
+        assert!(sent < clock2.timestamp());
+    }
+
+    #[test]
+    fn test_causality() {
+        assert_eq!(
+            Causality::Precede,
+            ts(100, 0, 1).causality_cmp(&ts(100, 1, 2))
+        );
+        assert_eq!(
+            Causality::Succeed,
+            ts(101, 0, 1).causality_cmp(&ts(100, 1, 2))
+        );
+        assert_eq!(
+            Causality::Equal,
+            ts(100, 1, 1).causality_cmp(&ts(100, 1, 1))
+        );
+        assert_eq!(
+            Causality::Concurrent,
+            ts(100, 1, 1).causality_cmp(&ts(100, 1, 2))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("100.2@1", format!("{}", ts(100, 2, 1)));
+    }
+}
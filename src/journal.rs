@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+
+use crate::CmRDT;
+
+/// A CRDT together with the log of the operations applied to it, for write-ahead logging.
+///
+/// Journaling requires `Clone` operations: the log keeps a copy of every applied operation.
+pub struct OpJournal<T: CmRDT> {
+    crdt: T,
+    ops: Vec<T::Op>,
+}
+
+//
+// Constructors
+//
+
+impl<T: CmRDT + Default> Default for OpJournal<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+//
+// Implementation
+//
+
+impl<T: CmRDT> OpJournal<T> {
+    /// Constructs a journal for a CRDT, with an empty log.
+    pub fn new(crdt: T) -> Self {
+        Self {
+            crdt,
+            ops: Vec::new(),
+        }
+    }
+
+    /// Rebuilds a CRDT by applying a sequence of operations to its default state.
+    pub fn replay(ops: impl IntoIterator<Item = T::Op>) -> T
+    where
+        T: Default,
+    {
+        let mut crdt = T::default();
+        for op in ops {
+            crdt.apply(op);
+        }
+        crdt
+    }
+
+    /// Returns the CRDT.
+    pub fn crdt(&self) -> &T {
+        &self.crdt
+    }
+
+    /// Returns the logged operations, the oldest first.
+    pub fn ops(&self) -> &[T::Op] {
+        &self.ops
+    }
+
+    /// Returns the number of logged operations.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns `true` if the log contains no operations.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Discards the oldest `n` operations, typically once they are covered by a snapshot.
+    pub fn truncate_before(&mut self, n: usize) {
+        self.ops.drain(..n.min(self.ops.len()));
+    }
+
+    /// Consumes the journal and returns the CRDT and the logged operations.
+    pub fn into_parts(self) -> (T, Vec<T::Op>) {
+        (self.crdt, self.ops)
+    }
+}
+
+impl<T: CmRDT> OpJournal<T>
+where
+    T::Op: Clone,
+{
+    /// Appends an operation to the log and then applies it to the CRDT.
+    pub fn apply_journaled(&mut self, op: T::Op) {
+        self.ops.push(op.clone());
+        self.crdt.apply(op);
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GCounter, LwwRegister, CRDT};
+
+    #[test]
+    fn test_apply_journaled() {
+        let mut journal: OpJournal<GCounter<i8, usize>> = OpJournal::default();
+        journal.apply_journaled(journal.crdt().incr_op(&1));
+        journal.apply_journaled(journal.crdt().incr_op(&1));
+        journal.apply_journaled(journal.crdt().incr_op(&2));
+
+        assert_eq!(3, journal.len());
+        assert_eq!(3, journal.crdt().value());
+        assert_eq!(2, journal.ops()[1].counter);
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut journal: OpJournal<LwwRegister<i8, usize, u32>> = OpJournal::default();
+        journal.apply_journaled(journal.crdt().write(&1, 10));
+        journal.apply_journaled(journal.crdt().write(&1, 20));
+
+        let (crdt, ops) = journal.into_parts();
+        let replayed = OpJournal::<LwwRegister<i8, usize, u32>>::replay(ops);
+        assert_eq!(crdt.value(), replayed.value());
+        assert_eq!(Some(&20), replayed.value());
+    }
+
+    #[test]
+    fn test_truncate_before() {
+        let mut journal: OpJournal<GCounter<i8, usize>> = OpJournal::default();
+        for _ in 0..5 {
+            journal.apply_journaled(journal.crdt().incr_op(&1));
+        }
+
+        journal.truncate_before(3);
+        assert_eq!(2, journal.len());
+        assert_eq!(4, journal.ops()[0].counter);
+
+        journal.truncate_before(10);
+        assert!(journal.is_empty());
+        assert_eq!(5, journal.crdt().value());
+    }
+}
@@ -0,0 +1,182 @@
+use core::convert::Infallible;
+use core::fmt::Display;
+
+use crate::{Actor, CmRDT, Counter, Dot};
+
+/// A Lamport clock, a single logical counter owned by an actor.
+pub struct LamportClock<A: Actor, C: Counter> {
+    dot: Dot<A, C>,
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter> Default for LamportClock<A, C> {
+    fn default() -> Self {
+        Self {
+            dot: Dot::new_zero(A::zero()),
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> From<Dot<A, C>> for LamportClock<A, C> {
+    fn from(dot: Dot<A, C>) -> Self {
+        Self { dot }
+    }
+}
+
+//
+// Formatting traits
+//
+
+impl<A: Actor + Display, C: Counter + Display> Display for LamportClock<A, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.dot)
+    }
+}
+
+//
+// Order traits
+//
+
+/// Two clocks are equal when their counters are equal, whatever their actors.
+impl<A: Actor, C: Counter> PartialEq for LamportClock<A, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dot.counter == other.dot.counter
+    }
+}
+
+/// The clocks are ordered by their counters alone, ignoring the actors.
+impl<A: Actor, C: Counter> PartialOrd for LamportClock<A, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.dot.counter.partial_cmp(&other.dot.counter)
+    }
+}
+
+//
+// CmRDT
+//
+
+impl<A: Actor, C: Counter> CmRDT for LamportClock<A, C> {
+    type Op = Dot<A, C>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, dot: Self::Op) {
+        self.receive(dot.counter);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter> LamportClock<A, C> {
+    /// Constructs a new `LamportClock` instance for an actor, with the counter set to zero.
+    pub fn new(actor: A) -> Self {
+        Self {
+            dot: Dot::new_zero(actor),
+        }
+    }
+
+    /// Returns the actor which owns the clock.
+    pub fn actor(&self) -> A {
+        self.dot.actor.clone()
+    }
+
+    /// Returns the current counter of the clock.
+    pub fn counter(&self) -> C {
+        self.dot.counter
+    }
+
+    /// Returns the current dot of the clock.
+    pub fn dot(&self) -> Dot<A, C> {
+        self.dot.clone()
+    }
+
+    /// Increments the clock for a local event and returns the new dot.
+    pub fn tick(&mut self) -> Dot<A, C> {
+        self.dot.incr_assign();
+        self.dot.clone()
+    }
+
+    /// Advances the clock past the counter received from a remote actor.
+    pub fn receive(&mut self, remote: C) {
+        if self.dot.counter < remote {
+            self.dot.counter = remote;
+        }
+        self.dot.incr_assign();
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_new() {
+        let clock = LamportClock::<i8, u64>::new(1);
+        assert_eq!(1, clock.actor());
+        assert_eq!(0, clock.counter());
+    }
+
+    #[test]
+    fn test_default() {
+        let mut clock1 = LamportClock::<i8, u64>::default();
+        let mut clock2 = LamportClock::<i8, u64>::new(0);
+        assert_eq!(clock2.counter(), clock1.counter());
+        assert_eq!(clock2.tick().counter, clock1.tick().counter);
+        assert_eq!(1, clock1.counter());
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut clock = LamportClock::<i8, u64>::new(1);
+        assert!(clock.tick() == Dot::new(1, 1));
+        assert!(clock.tick() == Dot::new(1, 2));
+        assert_eq!(2, clock.counter());
+    }
+
+    #[test]
+    fn test_receive() {
+        let mut clock = LamportClock::<i8, u64>::new(1);
+        clock.receive(5);
+        assert_eq!(6, clock.counter());
+
+        clock.receive(2);
+        assert_eq!(7, clock.counter());
+    }
+
+    #[quickcheck]
+    fn test_apply(local: u32, remote: u32) -> bool {
+        let mut clock: LamportClock<i8, u64> = Dot::new(1, local as u64).into();
+        clock.apply(Dot::new(2, remote as u64));
+        clock.counter() == local.max(remote) as u64 + 1 && clock.actor() == 1
+    }
+
+    #[test]
+    fn test_order() {
+        let clock1: LamportClock<i8, u64> = Dot::new(1, 5).into();
+        let clock2: LamportClock<i8, u64> = Dot::new(2, 5).into();
+        let clock3: LamportClock<i8, u64> = Dot::new(1, 6).into();
+
+        assert!(clock1 == clock2);
+        assert!(clock1 < clock3);
+        assert!(clock3 > clock2);
+    }
+
+    #[test]
+    fn test_display() {
+        let clock: LamportClock<i8, u64> = Dot::new(1, 5).into();
+        assert_eq!("1:5", format!("{}", clock));
+    }
+}
@@ -2,19 +2,80 @@
 //!
 #![deny(missing_docs)]
 #![deny(unreachable_pub)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 mod actors;
+mod bounded_counter;
+mod compact;
+mod context;
 mod counters;
+mod delivery;
 mod dot;
+mod dotset;
+mod dvvset;
+mod flag;
 mod gcounter;
+mod gmultiset;
+mod graph;
+mod gset;
+#[cfg(feature = "std")]
+mod hash_vclock;
+mod hlc;
+mod journal;
+mod lamport;
+mod lww_map;
+mod lww_register;
+mod macros;
+mod merge_policy;
+mod mv_register;
+mod ormap;
+mod orset;
 mod pncounter;
+mod pnmultiset;
+mod rga;
+mod small_vclock;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tpset;
 mod traits;
+#[cfg(feature = "uuid")]
+mod uuid_actor;
 mod vclock;
+mod whitelisted_vclock;
 
-pub use actors::*;
+pub use bounded_counter::*;
+pub use compact::*;
+pub use context::*;
 pub use counters::*;
+pub use delivery::*;
 pub use dot::*;
+pub use dotset::*;
+pub use dvvset::*;
+pub use flag::*;
 pub use gcounter::*;
+pub use gmultiset::*;
+pub use graph::*;
+pub use gset::*;
+#[cfg(feature = "std")]
+pub use hash_vclock::*;
+pub use hlc::*;
+pub use journal::*;
+pub use lamport::*;
+pub use lww_map::*;
+pub use lww_register::*;
+pub use merge_policy::*;
+pub use mv_register::*;
+pub use ormap::*;
+pub use orset::*;
 pub use pncounter::*;
+pub use pnmultiset::*;
+pub use rga::*;
+pub use small_vclock::*;
+pub use tpset::*;
 pub use traits::*;
+#[cfg(feature = "uuid")]
+pub use uuid_actor::*;
 pub use vclock::*;
+pub use whitelisted_vclock::*;
@@ -0,0 +1,243 @@
+use alloc::collections::BTreeMap;
+use core::convert::Infallible;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, LwwRegister, VClock};
+
+/// A last-write-wins map, where every key holds a `LwwRegister`.
+///
+/// A remove writes a tombstone in the register of the key, so a remove and a concurrent set
+/// are resolved by the same last-write-wins rule as two concurrent sets.
+///
+/// The dots are Lamport timestamps, as in `LwwRegister`: a set or a remove made after
+/// observing another write always wins over it.
+pub struct LwwMap<A: Actor, C: Counter, K: Ord, V> {
+    clock: VClock<A, C>,
+    registers: BTreeMap<K, LwwRegister<A, C, Option<V>>>,
+}
+
+/// The operations supported by a `LwwMap`.
+#[derive(Clone)]
+pub enum LwwMapOp<A: Actor, C: Counter, K: Ord, V> {
+    /// Sets the value of a key.
+    Set {
+        /// The dot which timestamps the write.
+        dot: Dot<A, C>,
+        /// The written key.
+        key: K,
+        /// The written value.
+        value: V,
+    },
+    /// Removes a key by writing a tombstone.
+    Remove {
+        /// The dot which timestamps the tombstone.
+        dot: Dot<A, C>,
+        /// The removed key.
+        key: K,
+    },
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, K: Ord, V> Default for LwwMap<A, C, K, V> {
+    fn default() -> Self {
+        Self {
+            clock: Default::default(),
+            registers: BTreeMap::new(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter, K: Ord, V> CvRDT for LwwMap<A, C, K, V> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.clock.merge(other.clock);
+
+        for (key, register) in other.registers {
+            self.registers.entry(key).or_default().merge(register);
+        }
+    }
+}
+
+impl<A: Actor, C: Counter, K: Ord, V> CmRDT for LwwMap<A, C, K, V> {
+    type Op = LwwMapOp<A, C, K, V>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        let (dot, key, value) = match op {
+            LwwMapOp::Set { dot, key, value } => (dot, key, Some(value)),
+            LwwMapOp::Remove { dot, key } => (dot, key, None),
+        };
+
+        self.clock.merge(dot.clone().into());
+        self.registers.entry(key).or_default().apply((dot, value));
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, K: Ord, V> LwwMap<A, C, K, V> {
+    /// Constructs a new `LwwMap` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which sets the value of a key on behalf of an actor.
+    pub fn set(&self, actor: &A, key: K, value: V) -> LwwMapOp<A, C, K, V> {
+        LwwMapOp::Set {
+            dot: self.next_dot(actor),
+            key,
+            value,
+        }
+    }
+
+    /// Returns the operation which removes a key on behalf of an actor.
+    pub fn remove(&self, actor: &A, key: K) -> LwwMapOp<A, C, K, V> {
+        LwwMapOp::Remove {
+            dot: self.next_dot(actor),
+            key,
+        }
+    }
+
+    /// Returns the live value of a given key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.registers
+            .get(key)
+            .and_then(|register| register.value())
+            .and_then(|value| value.as_ref())
+    }
+
+    /// Determines if the map contains a live value for a given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns an iterator over the live keys and their values, in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.registers.iter().filter_map(|(key, register)| {
+            register
+                .value()
+                .and_then(|value| value.as_ref())
+                .map(|value| (key, value))
+        })
+    }
+
+    /// Returns a dot for an actor whose counter is greater than every counter seen by the map.
+    fn next_dot(&self, actor: &A) -> Dot<A, C> {
+        let counter = self.clock.max_counter().unwrap_or_else(C::zero).incr();
+        Dot::new(actor.clone(), counter)
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let map: LwwMap<i8, usize, &str, u32> = LwwMap::default();
+        assert_eq!(0, map.iter().count());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut map: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map.apply(map.set(&1, "a", 10));
+        map.apply(map.set(&1, "b", 20));
+        map.apply(map.set(&1, "a", 30));
+
+        assert_eq!(Some(&30), map.get(&"a"));
+        assert_eq!(Some(&20), map.get(&"b"));
+        assert_eq!(None, map.get(&"c"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map.apply(map.set(&1, "a", 10));
+        map.apply(map.set(&1, "b", 20));
+        map.apply(map.remove(&1, "a"));
+
+        assert!(!map.contains_key(&"a"));
+        assert_eq!(vec![(&"b", &20)], map.iter().collect::<Vec<_>>());
+
+        map.apply(map.set(&1, "a", 30));
+        assert_eq!(Some(&30), map.get(&"a"));
+    }
+
+    #[test]
+    fn test_apply_stale() {
+        let mut map: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        let op = map.set(&1, "a", 10);
+        map.apply(map.set(&1, "a", 10));
+        map.apply(map.remove(&1, "a"));
+        map.apply(op);
+
+        assert!(!map.contains_key(&"a"));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut map1: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map1.apply(map1.set(&1, "a", 10));
+        map1.apply(map1.set(&1, "b", 20));
+
+        let mut map2: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map2.apply(map2.set(&2, "a", 30));
+        map2.apply(map2.set(&2, "c", 40));
+
+        map1.merge(map2);
+        assert_eq!(Some(&30), map1.get(&"a"));
+        assert_eq!(Some(&20), map1.get(&"b"));
+        assert_eq!(Some(&40), map1.get(&"c"));
+    }
+
+    #[test]
+    fn test_remove_after_remote_set() {
+        let mut map1: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        let mut map2: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map2.apply(map2.set(&2, "a", 10));
+        map1.merge(map2);
+
+        map1.apply(map1.remove(&1, "a"));
+        assert!(!map1.contains_key(&"a"));
+
+        let mut map3: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map3.apply(map3.set(&2, "a", 10));
+        map3.merge(map1);
+        assert!(!map3.contains_key(&"a"));
+    }
+
+    #[test]
+    fn test_merge_remove() {
+        let mut map1: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map1.apply(map1.set(&1, "a", 10));
+
+        let mut map2: LwwMap<i8, usize, &str, u32> = LwwMap::new();
+        map2.apply(map2.set(&1, "a", 10));
+        map2.apply(map2.remove(&1, "a"));
+
+        map1.merge(map2);
+        assert!(!map1.contains_key(&"a"));
+    }
+}
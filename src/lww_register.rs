@@ -0,0 +1,197 @@
+use core::convert::Infallible;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, VClock};
+
+/// A last-write-wins register.
+///
+/// Every write is timestamped with a `dot` whose counter is greater than every counter seen
+/// by the register, so the dots act as Lamport timestamps. The write with the greater counter
+/// wins, and ties between different actors go to the greater actor.
+pub struct LwwRegister<A: Actor, C: Counter, V> {
+    clock: VClock<A, C>,
+    entry: Option<(Dot<A, C>, V)>,
+}
+
+/// The operation for a `LwwRegister`, a dot acting as a timestamp and the written value.
+pub type LwwRegisterOp<A, C, V> = (Dot<A, C>, V);
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, V> Default for LwwRegister<A, C, V> {
+    fn default() -> Self {
+        Self {
+            clock: Default::default(),
+            entry: None,
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter, V> CvRDT for LwwRegister<A, C, V> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.clock.merge(other.clock);
+        if let Some((dot, value)) = other.entry {
+            self.write_if_newer(dot, value);
+        }
+    }
+}
+
+impl<A: Actor, C: Counter, V> CmRDT for LwwRegister<A, C, V> {
+    type Op = LwwRegisterOp<A, C, V>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        let (dot, value) = op;
+        self.clock.merge(dot.clone().into());
+        self.write_if_newer(dot, value);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, V> LwwRegister<A, C, V> {
+    /// Constructs a new `LwwRegister` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which writes a value on behalf of an actor.
+    pub fn write(&self, actor: &A, value: V) -> LwwRegisterOp<A, C, V> {
+        let counter = self.clock.max_counter().unwrap_or_else(C::zero).incr();
+        (Dot::new(actor.clone(), counter), value)
+    }
+
+    /// Returns the current value of the register.
+    pub fn value(&self) -> Option<&V> {
+        self.entry.as_ref().map(|(_, value)| value)
+    }
+
+    /// Returns the dot of the current value of the register.
+    pub fn dot(&self) -> Option<Dot<A, C>> {
+        self.entry.as_ref().map(|(dot, _)| dot.clone())
+    }
+
+    fn write_if_newer(&mut self, dot: Dot<A, C>, value: V) {
+        let newer = match &self.entry {
+            None => true,
+            Some((current, _)) => {
+                dot.counter > current.counter
+                    || (dot.counter == current.counter && dot.actor > current.actor)
+            }
+        };
+
+        if newer {
+            self.entry = Some((dot, value));
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_default() {
+        let reg: LwwRegister<i8, usize, &str> = LwwRegister::default();
+        assert_eq!(None, reg.value());
+    }
+
+    #[quickcheck]
+    fn test_write(actor: i8, value: u32) -> bool {
+        let mut reg: LwwRegister<i8, usize, u32> = LwwRegister::new();
+        reg.apply(reg.write(&actor, value));
+        reg.value() == Some(&value)
+    }
+
+    #[test]
+    fn test_overwrite() {
+        let mut reg: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        reg.apply(reg.write(&1, "a"));
+        reg.apply(reg.write(&1, "b"));
+        assert_eq!(Some(&"b"), reg.value());
+    }
+
+    #[test]
+    fn test_apply_stale() {
+        let mut reg: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        let op1 = reg.write(&1, "a");
+        reg.apply(reg.write(&1, "a"));
+        reg.apply(reg.write(&1, "b"));
+        reg.apply(op1);
+        assert_eq!(Some(&"b"), reg.value());
+    }
+
+    #[test]
+    fn test_merge_concurrent() {
+        let mut reg1: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        reg1.apply(reg1.write(&1, "a"));
+
+        let mut reg2: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        reg2.apply(reg2.write(&2, "b"));
+
+        let mut reg3: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        reg3.apply(reg3.write(&2, "b"));
+        reg3.merge({
+            let mut reg = LwwRegister::new();
+            reg.apply(reg.write(&1, "a"));
+            reg
+        });
+
+        reg1.merge(reg2);
+        assert_eq!(Some(&"b"), reg1.value());
+        assert_eq!(Some(&"b"), reg3.value());
+    }
+
+    #[test]
+    fn test_write_after_remote() {
+        let mut reg1: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        let mut reg2: LwwRegister<i8, usize, &str> = LwwRegister::new();
+
+        let (dot, value) = reg2.write(&2, "x");
+        reg2.apply((dot, value));
+        reg1.apply((dot, value));
+
+        let (dot, value) = reg1.write(&1, "y");
+        reg1.apply((dot, value));
+        reg2.apply((dot, value));
+
+        assert_eq!(2, dot.counter);
+        assert_eq!(Some(&"y"), reg1.value());
+        assert_eq!(Some(&"y"), reg2.value());
+    }
+
+    #[test]
+    fn test_merge_newer() {
+        let mut reg1: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        reg1.apply(reg1.write(&1, "a"));
+
+        let mut reg2: LwwRegister<i8, usize, &str> = LwwRegister::new();
+        reg2.apply(reg2.write(&1, "a"));
+        reg2.apply(reg2.write(&1, "b"));
+
+        reg1.merge(reg2);
+        assert_eq!(Some(&"b"), reg1.value());
+    }
+}
@@ -0,0 +1,102 @@
+/// Defines a newtype actor over a primitive type.
+///
+/// The newtype implements `Actor`, `Zero`, `One`, `Display` and the conversions from and to the
+/// underlying type. An optional prefix is prepended to the value when displayed.
+///
+/// ```
+/// use rust_euklid::{define_actor, VClock};
+///
+/// define_actor!(pub NodeId, u64, prefix = "node-");
+///
+/// let clock: VClock<NodeId, u64> = vec![(NodeId(1), 10)].into();
+/// assert_eq!("<node-1:10>", format!("{}", clock));
+/// ```
+#[macro_export]
+macro_rules! define_actor {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $t:ty) => {
+        $crate::define_actor!($(#[$meta])* $vis $name, $t, prefix = "");
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident, $t:ty, prefix = $prefix:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name(pub $t);
+
+        impl From<$t> for $name {
+            fn from(value: $t) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $t {
+            fn from(actor: $name) -> Self {
+                actor.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}{}", $prefix, self.0)
+            }
+        }
+
+        impl $crate::Zero for $name {
+            fn zero() -> Self {
+                Self(<$t as $crate::Zero>::zero())
+            }
+        }
+
+        impl $crate::One for $name {
+            fn one() -> Self {
+                Self(<$t as $crate::One>::one())
+            }
+        }
+
+        impl $crate::Actor for $name {}
+    };
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dot, One, VClock, Zero};
+
+    define_actor!(NodeId, u64);
+    define_actor!(
+        /// A replica identifier.
+        ReplicaId,
+        u8,
+        prefix = "replica-"
+    );
+
+    #[test]
+    fn test_conversions() {
+        let node: NodeId = 7.into();
+        assert_eq!(NodeId(7), node);
+        assert_eq!(7, u64::from(node));
+    }
+
+    #[test]
+    fn test_identities() {
+        assert_eq!(NodeId(0), NodeId::zero());
+        assert_eq!(NodeId(1), NodeId::one());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("7", format!("{}", NodeId(7)));
+        assert_eq!("replica-3", format!("{}", ReplicaId(3)));
+    }
+
+    #[test]
+    fn test_vclock() {
+        let mut clock: VClock<ReplicaId, u64> = vec![(ReplicaId(1), 10)].into();
+        clock.incr_assign(&ReplicaId(2));
+
+        assert!(clock.contains_dot(&Dot::new(ReplicaId(1), 10)));
+        assert!(clock.contains_dot(&Dot::new(ReplicaId(2), 1)));
+        assert_eq!("<replica-1:10,replica-2:1>", format!("{}", clock));
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::collections::BTreeSet;
+
+use crate::{Actor, CvRDT};
+
+/// A strategy which resolves two states of a CRDT into one.
+pub trait MergePolicy<T: CvRDT> {
+    /// Returns the state which resolves the two given states.
+    fn resolve(&self, a: &T, b: &T) -> T;
+}
+
+/// The join of the two states, the same as `CvRDT::merge`.
+pub struct MaxMerge;
+
+/// The meet of the two states, the counters both states agree on.
+pub struct MinMerge;
+
+/// Joins the counters of the trusted actors, and keeps the local counters of everyone else.
+pub struct ActorPriorityMerge<A: Actor> {
+    trusted: BTreeSet<A>,
+}
+
+impl<T: CvRDT + Clone> MergePolicy<T> for MaxMerge {
+    fn resolve(&self, a: &T, b: &T) -> T {
+        let mut resolved = a.clone();
+        resolved.merge(b.clone());
+        resolved
+    }
+}
+
+impl<A: Actor> FromIterator<A> for ActorPriorityMerge<A> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<A: Actor> ActorPriorityMerge<A> {
+    /// Constructs a policy which trusts the given actors.
+    pub fn new(trusted: impl IntoIterator<Item = A>) -> Self {
+        Self {
+            trusted: trusted.into_iter().collect(),
+        }
+    }
+
+    /// Determines if the counters of an actor are taken from the other state.
+    pub fn is_trusted(&self, actor: &A) -> bool {
+        self.trusted.contains(actor)
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VClock;
+
+    #[test]
+    fn test_max_merge() {
+        let a: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let b: VClock<i8, usize> = vec![(1, 5), (3, 30)].into();
+
+        assert!(MaxMerge.resolve(&a, &b) == vec![(1, 10), (2, 20), (3, 30)].into());
+    }
+
+    #[test]
+    fn test_actor_priority() {
+        let policy: ActorPriorityMerge<i8> = [1, 2].into_iter().collect();
+        assert!(policy.is_trusted(&1));
+        assert!(!policy.is_trusted(&3));
+    }
+}
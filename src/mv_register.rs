@@ -0,0 +1,175 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering::{Equal, Less};
+use core::convert::Infallible;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, UpdateAssign, VClock};
+
+/// A multi-value register, which keeps all the concurrently assigned values.
+///
+/// Each value is tagged with the `VClock` of the assignment which produced it. An assignment
+/// replaces all the values it has observed and keeps the ones that are concurrent to it.
+pub struct MVRegister<A: Actor, C: Counter, V> {
+    vals: Vec<(VClock<A, C>, V)>,
+}
+
+/// The operation for a `MVRegister`, a value tagged with the clock of the assignment.
+pub struct MVRegisterOp<A: Actor, C: Counter, V> {
+    /// The clock of the assignment.
+    pub clock: VClock<A, C>,
+    /// The assigned value.
+    pub value: V,
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, V> Default for MVRegister<A, C, V> {
+    fn default() -> Self {
+        Self { vals: Vec::new() }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter, V> CvRDT for MVRegister<A, C, V> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (clock, value) in other.vals {
+            self.assign_if_not_observed(clock, value);
+        }
+    }
+}
+
+impl<A: Actor, C: Counter, V> CmRDT for MVRegister<A, C, V> {
+    type Op = MVRegisterOp<A, C, V>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        self.assign_if_not_observed(op.clock, op.value);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, V> MVRegister<A, C, V> {
+    /// Constructs a new `MVRegister` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which assigns a value on behalf of an actor.
+    ///
+    /// The assignment observes all the values currently stored in the register.
+    pub fn assign(&self, actor: &A, value: V) -> MVRegisterOp<A, C, V> {
+        let mut clock = self.read_clock();
+        clock.incr_assign(actor);
+        MVRegisterOp { clock, value }
+    }
+
+    /// Returns an iterator over the concurrent values of the register.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.vals.iter().map(|(_, value)| value)
+    }
+
+    /// Returns the clock which summarizes all the values stored in the register.
+    pub fn read_clock(&self) -> VClock<A, C> {
+        let mut clock = VClock::default();
+        for (c, _) in self.vals.iter() {
+            c.iter().for_each(|dot| {
+                if !clock.contains_greater(&dot.actor, &dot.counter) {
+                    clock.upd_assign(dot)
+                }
+            });
+        }
+        clock
+    }
+
+    fn assign_if_not_observed(&mut self, clock: VClock<A, C>, value: V) {
+        if self.vals.iter().any(|(c, _)| clock <= *c) {
+            return;
+        }
+
+        self.vals
+            .retain(|(c, _)| !matches!(c.partial_cmp(&clock), Some(Less | Equal)));
+        self.vals.push((clock, value));
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let reg: MVRegister<i8, usize, &str> = MVRegister::default();
+        assert_eq!(0, reg.values().count());
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut reg: MVRegister<i8, usize, &str> = MVRegister::new();
+        reg.apply(reg.assign(&1, "a"));
+        reg.apply(reg.assign(&2, "b"));
+        assert_eq!(vec![&"b"], reg.values().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_apply_idempotent() {
+        let mut reg: MVRegister<i8, usize, &str> = MVRegister::new();
+        reg.apply(reg.assign(&1, "a"));
+        reg.apply(MVRegisterOp {
+            clock: (1, 1).into(),
+            value: "a",
+        });
+        assert_eq!(vec![&"a"], reg.values().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_merge_concurrent() {
+        let mut reg1: MVRegister<i8, usize, &str> = MVRegister::new();
+        reg1.apply(reg1.assign(&1, "a"));
+
+        let mut reg2: MVRegister<i8, usize, &str> = MVRegister::new();
+        reg2.apply(reg2.assign(&2, "b"));
+
+        reg1.merge(reg2);
+        assert_eq!(vec![&"a", &"b"], reg1.values().collect::<Vec<_>>());
+
+        reg1.apply(reg1.assign(&1, "c"));
+        assert_eq!(vec![&"c"], reg1.values().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_merge_observed() {
+        let mut reg1: MVRegister<i8, usize, &str> = MVRegister::new();
+        reg1.apply(reg1.assign(&1, "a"));
+
+        let mut reg2: MVRegister<i8, usize, &str> = MVRegister::new();
+        reg2.apply(MVRegisterOp {
+            clock: (1, 1).into(),
+            value: "a",
+        });
+        reg2.apply(reg2.assign(&2, "b"));
+
+        reg1.merge(reg2);
+        assert_eq!(vec![&"b"], reg1.values().collect::<Vec<_>>());
+    }
+}
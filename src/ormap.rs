@@ -0,0 +1,373 @@
+use alloc::collections::BTreeMap;
+use core::convert::Infallible;
+
+use crate::{Actor, CausalContext, CmRDT, Counter, CvRDT, Dot, VClock};
+
+/// An observed-remove map with add-wins semantics, whose values are CRDTs themselves.
+///
+/// Every update tags the key with a fresh `dot` taken from a causal context, usually the one
+/// read from the map. A remove only tombstones the dots of the key covered by the causal context
+/// it is given, so a concurrent update, or one the caller has not seen, wins.
+///
+/// A value which has been fully removed is dropped, so a key added again starts from the
+/// default value. When an update wins over a concurrent remove, the value is not reset: it keeps
+/// the contributions made before the remove.
+pub struct ORMap<A: Actor, C: Counter, K: Ord, V: CvRDT> {
+    context: CausalContext<A, C>,
+    entries: BTreeMap<K, ORMapEntry<A, C, V>>,
+    removes: BTreeMap<K, VClock<A, C>>,
+}
+
+struct ORMapEntry<A: Actor, C: Counter, V: CvRDT> {
+    dots: VClock<A, C>,
+    value: V,
+}
+
+/// The operations supported by an `ORMap`.
+pub enum ORMapOp<A: Actor, C: Counter, K: Ord, V: CvRDT> {
+    /// Joins a value into the value of a key, tagging the key with a fresh dot.
+    Update {
+        /// The dot which tags the key.
+        dot: Dot<A, C>,
+        /// The updated key.
+        key: K,
+        /// The updated value, joined into the value of the key.
+        value: V,
+    },
+    /// Removes a key by tombstoning its observed dots.
+    Remove {
+        /// The dots observed for the key.
+        clock: VClock<A, C>,
+        /// The removed key.
+        key: K,
+    },
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, K: Ord, V: CvRDT> Default for ORMap<A, C, K, V> {
+    fn default() -> Self {
+        Self {
+            context: Default::default(),
+            entries: BTreeMap::new(),
+            removes: BTreeMap::new(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter, K: Ord, V: CvRDT> CvRDT for ORMap<A, C, K, V> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        let mut clock: VClock<A, C> = core::mem::take(&mut self.context).into();
+        clock.merge(other.context.into());
+        self.context = clock.into();
+
+        for (key, clock) in other.removes {
+            self.removes.entry(key).or_default().merge(clock);
+        }
+
+        let removes = &self.removes;
+        self.entries
+            .retain(|key, entry| Self::is_alive(removes.get(key), &entry.dots));
+
+        for (key, entry) in other.entries {
+            if !Self::is_alive(self.removes.get(&key), &entry.dots) {
+                continue;
+            }
+
+            match self.entries.get_mut(&key) {
+                Some(current) => {
+                    current.dots.merge(entry.dots);
+                    current.value.merge(entry.value);
+                }
+                None => {
+                    self.entries.insert(key, entry);
+                }
+            }
+        }
+    }
+}
+
+impl<A: Actor, C: Counter, K: Ord, V: CvRDT> CmRDT for ORMap<A, C, K, V> {
+    type Op = ORMapOp<A, C, K, V>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        match op {
+            ORMapOp::Update { dot, key, value } => {
+                self.context.advance(dot.clone());
+
+                let dots: VClock<A, C> = dot.into();
+                if !Self::is_alive(self.removes.get(&key), &dots) {
+                    return;
+                }
+
+                match self.entries.get_mut(&key) {
+                    Some(current) => {
+                        current.dots.merge(dots);
+                        current.value.merge(value);
+                    }
+                    None => {
+                        self.entries.insert(key, ORMapEntry { dots, value });
+                    }
+                }
+            }
+            ORMapOp::Remove { clock, key } => {
+                let mut removes = self.removes.remove(&key).unwrap_or_default();
+                removes.merge(clock);
+
+                if self
+                    .entries
+                    .get(&key)
+                    .is_some_and(|entry| !Self::is_alive(Some(&removes), &entry.dots))
+                {
+                    self.entries.remove(&key);
+                }
+                self.removes.insert(key, removes);
+            }
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, K: Ord, V: CvRDT> ORMap<A, C, K, V> {
+    /// Constructs a new `ORMap` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the map contains no live keys.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of live keys in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the causal context, the events observed by the map.
+    pub fn context(&self) -> &CausalContext<A, C> {
+        &self.context
+    }
+
+    /// Returns the operation which applies on behalf of an actor a mutation to the value of a
+    /// key, starting from the default value if the key is not in the map. The key is tagged with
+    /// the next dot of the actor in the causal context.
+    pub fn update(
+        &self,
+        ctx: &CausalContext<A, C>,
+        actor: &A,
+        key: K,
+        f: impl FnOnce(&mut V),
+    ) -> ORMapOp<A, C, K, V>
+    where
+        V: Clone + Default,
+    {
+        let mut value = self.get(&key).cloned().unwrap_or_default();
+        f(&mut value);
+
+        ORMapOp::Update {
+            dot: ctx.clock().dot_incremented(actor),
+            key,
+            value,
+        }
+    }
+
+    /// Returns the operation which removes a key by tombstoning the dots of the key covered by
+    /// the causal context.
+    pub fn remove(&self, ctx: &CausalContext<A, C>, key: K) -> ORMapOp<A, C, K, V> {
+        let clock = match self.entries.get(&key) {
+            Some(entry) => entry.dots.intersection(ctx.clock()),
+            None => VClock::default(),
+        };
+
+        ORMapOp::Remove { clock, key }
+    }
+
+    /// Determines if the map contains a given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Returns the value of a given key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    /// Returns an iterator over the live keys and their values, in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.entries.iter().map(|(key, entry)| (key, &entry.value))
+    }
+
+    /// Determines if any of the dots which tag a key has not been tombstoned.
+    fn is_alive(removes: Option<&VClock<A, C>>, dots: &VClock<A, C>) -> bool {
+        match removes {
+            Some(removes) => dots
+                .iter()
+                .any(|dot| !removes.contains_greater(&dot.actor, &dot.counter)),
+            None => !dots.is_empty(),
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GCounter, CRDT};
+
+    type Map = ORMap<i8, usize, &'static str, GCounter<i8, usize>>;
+
+    fn update(map: &mut Map, actor: i8, key: &'static str) {
+        map.apply(map.update(map.context(), &actor, key, |gc| gc.incr_assign(&actor)));
+    }
+
+    fn remove(map: &mut Map, key: &'static str) {
+        map.apply(map.remove(map.context(), key));
+    }
+
+    #[test]
+    fn test_default() {
+        let map = Map::default();
+        assert!(map.is_empty());
+        assert_eq!(0, map.iter().count());
+    }
+
+    #[test]
+    fn test_update() {
+        let mut map = Map::new();
+        update(&mut map, 1, "a");
+        update(&mut map, 1, "a");
+        update(&mut map, 2, "b");
+
+        assert_eq!(Some(2), map.get(&"a").map(|gc| gc.value()));
+        assert_eq!(Some(1), map.get(&"b").map(|gc| gc.value()));
+        assert!(map.get(&"c").is_none());
+        assert!(map.context().has_seen(&crate::Dot::new(1, 2)));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map = Map::new();
+        update(&mut map, 1, "a");
+        update(&mut map, 1, "b");
+        remove(&mut map, "a");
+
+        assert!(!map.contains_key(&"a"));
+        assert_eq!(vec![&"b"], map.iter().map(|(k, _)| k).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_remove_unseen_update() {
+        let mut map = Map::new();
+        update(&mut map, 1, "a");
+        let ctx: CausalContext<i8, usize> = map.context().clock().clone().into();
+        update(&mut map, 1, "a");
+
+        map.apply(map.remove(&ctx, "a"));
+        assert_eq!(Some(2), map.get(&"a").map(|gc| gc.value()));
+    }
+
+    #[test]
+    fn test_readd_resets_value() {
+        let mut map1 = Map::new();
+        update(&mut map1, 1, "a");
+        update(&mut map1, 1, "a");
+
+        let mut map2 = Map::new();
+        update(&mut map2, 1, "a");
+        update(&mut map2, 1, "a");
+
+        remove(&mut map1, "a");
+        update(&mut map1, 1, "a");
+        assert_eq!(Some(1), map1.get(&"a").map(|gc| gc.value()));
+
+        map1.merge(map2);
+        assert_eq!(Some(1), map1.get(&"a").map(|gc| gc.value()));
+    }
+
+    #[test]
+    fn test_apply_update_after_remove() {
+        let mut map1 = Map::new();
+        update(&mut map1, 1, "a");
+
+        let map2 = Map::new();
+        let op = map2.update(map2.context(), &1, "a", |gc| gc.incr_assign(&1));
+
+        remove(&mut map1, "a");
+        map1.apply(op);
+        assert!(!map1.contains_key(&"a"));
+    }
+
+    #[test]
+    fn test_merge_joins_values() {
+        let mut map1 = Map::new();
+        update(&mut map1, 1, "a");
+
+        let mut map2 = Map::new();
+        update(&mut map2, 2, "a");
+        update(&mut map2, 2, "a");
+
+        map1.merge(map2);
+        assert_eq!(Some(3), map1.get(&"a").map(|gc| gc.value()));
+    }
+
+    #[test]
+    fn test_merge_add_wins() {
+        let mut map1 = Map::new();
+        update(&mut map1, 1, "a");
+
+        let mut map2 = Map::new();
+        map2.merge({
+            let mut map = Map::new();
+            update(&mut map, 1, "a");
+            map
+        });
+
+        remove(&mut map1, "a");
+        update(&mut map2, 2, "a");
+
+        map1.merge(map2);
+        assert!(map1.contains_key(&"a"));
+    }
+
+    #[test]
+    fn test_merge_observed_remove() {
+        let mut map1 = Map::new();
+        update(&mut map1, 1, "a");
+
+        let mut map2 = Map::new();
+        map2.merge({
+            let mut map = Map::new();
+            update(&mut map, 1, "a");
+            map
+        });
+        remove(&mut map2, "a");
+
+        map1.merge(map2);
+        assert!(!map1.contains_key(&"a"));
+        assert!(map1.is_empty());
+    }
+}
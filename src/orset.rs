@@ -0,0 +1,226 @@
+use alloc::collections::BTreeMap;
+use core::convert::Infallible;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, UpdateAssign, VClock};
+
+/// An observed-remove set with add-wins semantics.
+///
+/// Every insert tags the element with a fresh `dot` taken from the causal context of the set.
+/// A remove only tombstones the dots observed for that element, so a concurrent insert wins.
+pub struct ORSet<A: Actor, C: Counter, E: Ord> {
+    clock: VClock<A, C>,
+    adds: BTreeMap<E, VClock<A, C>>,
+    removes: BTreeMap<E, VClock<A, C>>,
+}
+
+/// The operations supported by an `ORSet`.
+pub enum ORSetOp<A: Actor, C: Counter, E: Ord> {
+    /// Inserts an element tagged with a fresh dot.
+    Insert {
+        /// The dot which tags the element.
+        dot: Dot<A, C>,
+        /// The inserted element.
+        element: E,
+    },
+    /// Removes an element by tombstoning its observed dots.
+    Remove {
+        /// The dots observed for the element.
+        clock: VClock<A, C>,
+        /// The removed element.
+        element: E,
+    },
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, E: Ord> Default for ORSet<A, C, E> {
+    fn default() -> Self {
+        Self {
+            clock: Default::default(),
+            adds: BTreeMap::new(),
+            removes: BTreeMap::new(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter, E: Ord> CvRDT for ORSet<A, C, E> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.clock.merge(other.clock);
+
+        for (element, clock) in other.adds {
+            self.adds.entry(element).or_default().merge(clock);
+        }
+
+        for (element, clock) in other.removes {
+            self.removes.entry(element).or_default().merge(clock);
+        }
+    }
+}
+
+impl<A: Actor, C: Counter, E: Ord> CmRDT for ORSet<A, C, E> {
+    type Op = ORSetOp<A, C, E>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        match op {
+            ORSetOp::Insert { dot, element } => {
+                self.clock.merge(dot.clone().into());
+                self.adds.entry(element).or_default().merge(dot.into());
+            }
+            ORSetOp::Remove { clock, element } => {
+                self.removes.entry(element).or_default().merge(clock);
+            }
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, E: Ord> ORSet<A, C, E> {
+    /// Constructs a new `ORSet` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which inserts an element on behalf of an actor.
+    pub fn insert(&self, actor: &A, element: E) -> ORSetOp<A, C, E> {
+        ORSetOp::Insert {
+            dot: self.clock.dot_incremented(actor),
+            element,
+        }
+    }
+
+    /// Returns the operation which removes all the observed instances of an element.
+    pub fn remove(&self, element: E) -> ORSetOp<A, C, E> {
+        let mut clock = VClock::default();
+        if let Some(adds) = self.adds.get(&element) {
+            adds.iter().for_each(|dot| clock.upd_assign(dot));
+        }
+
+        ORSetOp::Remove { clock, element }
+    }
+
+    /// Determines if the set contains a given element.
+    pub fn contains(&self, element: &E) -> bool {
+        self.adds
+            .get(element)
+            .is_some_and(|adds| self.is_alive(element, adds))
+    }
+
+    /// Returns an iterator over the live elements of the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &E> + '_ {
+        self.adds
+            .iter()
+            .filter(|(element, adds)| self.is_alive(element, adds))
+            .map(|(element, _)| element)
+    }
+
+    /// Determines if any of the dots which tag an element has not been tombstoned.
+    fn is_alive(&self, element: &E, adds: &VClock<A, C>) -> bool {
+        match self.removes.get(element) {
+            Some(removes) => adds
+                .iter()
+                .any(|dot| !removes.contains_greater(&dot.actor, &dot.counter)),
+            None => !adds.is_empty(),
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let set: ORSet<i8, usize, i8> = ORSet::default();
+        assert_eq!(0, set.iter().count());
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set: ORSet<i8, usize, &str> = ORSet::new();
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.insert(&2, "b"));
+
+        assert!(set.contains(&"a"));
+        assert!(set.contains(&"b"));
+        assert!(!set.contains(&"c"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set: ORSet<i8, usize, &str> = ORSet::new();
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.insert(&2, "b"));
+        set.apply(set.remove("a"));
+
+        assert!(!set.contains(&"a"));
+        assert_eq!(vec![&"b"], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_reinsert() {
+        let mut set: ORSet<i8, usize, &str> = ORSet::new();
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.remove("a"));
+        set.apply(set.insert(&1, "a"));
+
+        assert!(set.contains(&"a"));
+    }
+
+    #[test]
+    fn test_merge_add_wins() {
+        let mut set1: ORSet<i8, usize, &str> = ORSet::new();
+        set1.apply(set1.insert(&1, "a"));
+
+        let mut set2: ORSet<i8, usize, &str> = ORSet::new();
+        set2.merge({
+            let mut set = ORSet::new();
+            set.apply(set.insert(&1, "a"));
+            set
+        });
+
+        set1.apply(set1.remove("a"));
+        set2.apply(set2.insert(&2, "a"));
+
+        set1.merge(set2);
+        assert!(set1.contains(&"a"));
+    }
+
+    #[test]
+    fn test_merge_observed_remove() {
+        let mut set1: ORSet<i8, usize, &str> = ORSet::new();
+        set1.apply(set1.insert(&1, "a"));
+        set1.apply(set1.insert(&1, "b"));
+
+        let mut set2: ORSet<i8, usize, &str> = ORSet::new();
+        set2.apply(set2.insert(&2, "a"));
+        set2.apply(set2.remove("a"));
+
+        set1.merge(set2);
+        assert!(set1.contains(&"a"));
+        assert!(set1.contains(&"b"));
+    }
+}
@@ -1,6 +1,14 @@
-use std::fmt::{Debug, Display};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::{Add, AddAssign};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
-use crate::{Actor, CmRDT, Counter, CvRDT, Dot, GCounter, CRDT};
+use crate::{
+    Actor, ActorPriorityMerge, CmRDT, Counter, CvRDT, DeltaCRDT, Dot, GCounter, MergePolicy,
+    MinMerge, Snapshot, SnapshotError, CRDT, SNAPSHOT_VERSION,
+};
 
 /// PNCounter
 pub struct PNCounter<A: Actor, C: Counter> {
@@ -16,6 +24,32 @@ pub enum PNOperation<A: Actor, C: Counter> {
     Neg(Dot<A, C>),
 }
 
+/// The errors reported by a `PNCounter`.
+#[derive(Debug, PartialEq)]
+pub enum PNCounterError {
+    /// The decrements exceed the increments, so the value cannot be represented as unsigned.
+    Underflow {
+        /// The total of the increments.
+        positive: usize,
+        /// The total of the decrements.
+        negative: usize,
+    },
+}
+
+impl Display for PNCounterError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PNCounterError::Underflow { positive, negative } => write!(
+                f,
+                "the decrements ({}) exceed the increments ({})",
+                negative, positive
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PNCounterError {}
+
 //
 // Constructors
 //
@@ -29,6 +63,52 @@ impl<A: Actor, C: Counter> Default for PNCounter<A, C> {
     }
 }
 
+impl<A: Actor, C: Counter> Clone for PNCounter<A, C> {
+    fn clone(&self) -> Self {
+        Self {
+            pdots: self.pdots.clone(),
+            ndots: self.ndots.clone(),
+        }
+    }
+}
+
+//
+// Formatting traits
+//
+
+impl<A: Actor + Display, C: Counter + Display> Display for PNCounter<A, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "PNCounter<+{}, -{}>",
+            self.pdots.vclock, self.ndots.vclock
+        )
+    }
+}
+
+//
+// Order traits
+//
+
+impl<A: Actor, C: Counter> PartialEq for PNCounter<A, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pdots == other.pdots && self.ndots == other.ndots
+    }
+}
+
+impl<A: Actor, C: Counter> Eq for PNCounter<A, C> {}
+
+//
+// Hashing
+//
+
+impl<A: Actor + Hash, C: Counter + Hash> Hash for PNCounter<A, C> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.pdots.hash(state);
+        self.ndots.hash(state);
+    }
+}
+
 //
 // CvRDT, CmRDT, CRDT
 //
@@ -66,13 +146,84 @@ impl<A: Actor + Debug + Display, C: Counter + Debug + Display> CvRDT for PNCount
     }
 }
 
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> DeltaCRDT for PNCounter<A, C> {
+    fn delta_since(&self, checkpoint: &Self) -> Self {
+        Self {
+            pdots: self.pdots.delta_since(&checkpoint.pdots),
+            ndots: self.ndots.delta_since(&checkpoint.ndots),
+        }
+    }
+}
+
+/// The versioned state of a `PNCounter`, the increments and the decrements of every actor.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PNCounterSnapshot<A, C> {
+    /// The schema version of the snapshot.
+    pub version: u8,
+    /// The increments of every actor, in ascending actor order.
+    pub positive: Vec<(A, C)>,
+    /// The decrements of every actor, in ascending actor order.
+    pub negative: Vec<(A, C)>,
+}
+
+impl<A, C> From<(Vec<(A, C)>, Vec<(A, C)>)> for PNCounterSnapshot<A, C> {
+    fn from((positive, negative): (Vec<(A, C)>, Vec<(A, C)>)) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            positive,
+            negative,
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> Snapshot for PNCounter<A, C> {
+    type SnapshotData = PNCounterSnapshot<A, C>;
+
+    fn snapshot(&self) -> Self::SnapshotData {
+        (self.pdots.snapshot().dots, self.ndots.snapshot().dots).into()
+    }
+
+    fn restore(data: Self::SnapshotData) -> Result<Self, SnapshotError> {
+        if data.version > SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(data.version));
+        }
+
+        Ok(Self {
+            pdots: GCounter::restore(data.positive.into())?,
+            ndots: GCounter::restore(data.negative.into())?,
+        })
+    }
+}
+
 impl<A: Actor, C: Counter + Into<usize>> CRDT for PNCounter<A, C> {
-    type Output = usize;
+    type Output = isize;
 
+    /// Returns the increments minus the decrements, which can be negative.
     fn value(&self) -> Self::Output {
-        let p = self.pdots.value();
-        let n = self.ndots.value();
-        p - n
+        self.positive_value() as isize - self.negative_value() as isize
+    }
+}
+
+//
+// Operations
+//
+
+/// Merges in-place another counter, the same as `CvRDT::merge`. The contributions of every
+/// actor are joined, not summed.
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> AddAssign for PNCounter<A, C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.merge(rhs);
+    }
+}
+
+/// The merge of two counters, the same as `CvRDT::merge`.
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> Add for PNCounter<A, C> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
     }
 }
 
@@ -95,4 +246,494 @@ impl<A: Actor, C: Counter> PNCounter<A, C> {
     pub fn decr_assign(&mut self, actor: &A) {
         self.ndots.incr_assign(actor);
     }
+
+    /// Increments in-place the counter by a given step.
+    pub fn step_up(&mut self, actor: &A, step: C) {
+        self.pdots.step_up(actor, step);
+    }
+
+    /// Decrements in-place the counter by a given step.
+    pub fn step_down(&mut self, actor: &A, step: C) {
+        self.ndots.step_up(actor, step);
+    }
+
+    /// Removes an actor from the counter, returning its increments and decrements if it was
+    /// present.
+    ///
+    /// The removal is local only: merging with a replica which still knows the actor adds it
+    /// back, see `GCounter::remove_actor`.
+    pub fn remove_actor(&mut self, actor: &A) -> Option<(C, C)> {
+        match (
+            self.pdots.remove_actor(actor),
+            self.ndots.remove_actor(actor),
+        ) {
+            (None, None) => None,
+            (p, n) => Some((p.unwrap_or_else(C::zero), n.unwrap_or_else(C::zero))),
+        }
+    }
+
+    /// Replaces the increments and the decrements of an actor with the result of a function,
+    /// and returns them. Lowering either of them is local only, see `GCounter::update_actor`.
+    pub fn update_actor(&mut self, actor: &A, f: impl FnOnce(C, C) -> (C, C)) -> (C, C) {
+        let (p, n) = f(
+            self.pdots.value_for_actor(actor),
+            self.ndots.value_for_actor(actor),
+        );
+        (
+            self.pdots.update_actor(actor, |_| p),
+            self.ndots.update_actor(actor, |_| n),
+        )
+    }
+
+    /// Returns the operation which increments the counter.
+    pub fn incr_op(&self, actor: &A) -> PNOperation<A, C> {
+        PNOperation::Pos(self.pdots.incr_op(actor))
+    }
+
+    /// Returns the operation which decrements the counter.
+    pub fn decr_op(&self, actor: &A) -> PNOperation<A, C> {
+        PNOperation::Neg(self.ndots.incr_op(actor))
+    }
+
+    /// Returns the operation which increments the counter by a given step.
+    pub fn step_up_op(&self, actor: &A, step: C) -> PNOperation<A, C> {
+        PNOperation::Pos(self.pdots.step_up_op(actor, step))
+    }
+
+    /// Returns the operation which decrements the counter by a given step.
+    pub fn step_down_op(&self, actor: &A, step: C) -> PNOperation<A, C> {
+        PNOperation::Neg(self.ndots.step_up_op(actor, step))
+    }
+}
+
+impl<A: Actor, C: Counter + Into<usize>> PNCounter<A, C> {
+    /// Returns the total of the increments.
+    pub fn positive_value(&self) -> usize {
+        self.pdots.value()
+    }
+
+    /// Returns the total of the decrements.
+    pub fn negative_value(&self) -> usize {
+        self.ndots.value()
+    }
+
+    /// Returns the value of the counter as a signed integer, which can be negative.
+    pub fn signed_value(&self) -> i64 {
+        self.positive_value() as i64 - self.negative_value() as i64
+    }
+
+    /// Determines if the increments exceed the decrements.
+    pub fn is_positive(&self) -> bool {
+        self.positive_value() > self.negative_value()
+    }
+
+    /// Determines if the decrements exceed the increments.
+    pub fn is_negative(&self) -> bool {
+        self.positive_value() < self.negative_value()
+    }
+
+    /// Determines if the increments and the decrements cancel out.
+    pub fn is_balanced(&self) -> bool {
+        self.positive_value() == self.negative_value()
+    }
+
+    /// Returns the value of the counter as unsigned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decrements exceed the increments, see `validate_value`.
+    pub fn unsigned_value(&self) -> usize {
+        match self.validate_value() {
+            Ok(()) => self.positive_value() - self.negative_value(),
+            Err(err) => panic!("the value of the counter is negative: {}", err),
+        }
+    }
+
+    /// Returns the value of the counter, or zero if the decrements exceed the increments.
+    pub fn clamped_value(&self) -> usize {
+        self.positive_value().saturating_sub(self.negative_value())
+    }
+
+    /// Determines if the value of the counter can be represented as unsigned.
+    pub fn validate_value(&self) -> Result<(), PNCounterError> {
+        let positive = self.positive_value();
+        let negative = self.negative_value();
+
+        if negative > positive {
+            Err(PNCounterError::Underflow { positive, negative })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+//
+// Merge policies
+//
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> MergePolicy<PNCounter<A, C>>
+    for MinMerge
+{
+    fn resolve(&self, a: &PNCounter<A, C>, b: &PNCounter<A, C>) -> PNCounter<A, C> {
+        PNCounter {
+            pdots: self.resolve(&a.pdots, &b.pdots),
+            ndots: self.resolve(&a.ndots, &b.ndots),
+        }
+    }
+}
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> MergePolicy<PNCounter<A, C>>
+    for ActorPriorityMerge<A>
+{
+    fn resolve(&self, a: &PNCounter<A, C>, b: &PNCounter<A, C>) -> PNCounter<A, C> {
+        PNCounter {
+            pdots: self.resolve(&a.pdots, &b.pdots),
+            ndots: self.resolve(&a.ndots, &b.ndots),
+        }
+    }
+}
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> PNCounter<A, C> {
+    /// Merges another counter into this counter, resolving the two counters with a policy.
+    pub fn merge_with_policy<P: MergePolicy<Self>>(&mut self, other: Self, policy: &P) {
+        *self = policy.resolve(self, &other);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: Actor + Hash, C: Counter> PNCounter<A, C> {
+    /// Returns the increments and the decrements of every actor as `HashMap`s.
+    pub fn to_hashmap(&self) -> (HashMap<A, C>, HashMap<A, C>) {
+        (self.pdots.to_hashmap(), self.ndots.to_hashmap())
+    }
+
+    /// Constructs a counter from the increments and the decrements of every actor.
+    pub fn from_hashmap(positive: HashMap<A, C>, negative: HashMap<A, C>) -> Self {
+        Self {
+            pdots: GCounter::from_hashmap(positive),
+            ndots: GCounter::from_hashmap(negative),
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.incr_assign(&1);
+        pn.incr_assign(&1);
+        pn.decr_assign(&2);
+
+        assert_eq!("PNCounter<+<1:2>, -<2:1>>", pn.to_string());
+    }
+
+    #[test]
+    fn positive_negative_value() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.incr_assign(&1);
+        pn.incr_assign(&1);
+        pn.incr_assign(&2);
+        pn.decr_assign(&2);
+
+        assert_eq!(3, pn.positive_value());
+        assert_eq!(1, pn.negative_value());
+        assert_eq!(2, pn.value());
+    }
+
+    #[test]
+    fn step_up_down() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 10);
+        pn.step_down(&2, 3);
+        pn.step_down(&2, 4);
+
+        assert_eq!(10, pn.positive_value());
+        assert_eq!(7, pn.negative_value());
+        assert_eq!(3, pn.value());
+    }
+
+    #[test]
+    fn step_up_down_op() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.apply(pn.step_up_op(&1, 10));
+        pn.apply(pn.step_down_op(&2, 3));
+        pn.apply(pn.step_down_op(&2, 4));
+
+        assert_eq!(10, pn.positive_value());
+        assert_eq!(7, pn.negative_value());
+    }
+
+    #[test]
+    fn incr_decr_op() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        let op = pn.incr_op(&1);
+        assert_eq!(0, pn.positive_value());
+
+        pn.apply(op);
+        pn.apply(pn.incr_op(&1));
+        pn.apply(pn.decr_op(&2));
+
+        assert_eq!(2, pn.positive_value());
+        assert_eq!(1, pn.negative_value());
+    }
+
+    #[test]
+    fn signed_value() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 3);
+        pn.step_down(&2, 5);
+
+        assert_eq!(-2, pn.signed_value());
+        assert_eq!(0, pn.clamped_value());
+        assert_eq!(
+            Err(PNCounterError::Underflow {
+                positive: 3,
+                negative: 5
+            }),
+            pn.validate_value()
+        );
+    }
+
+    #[test]
+    fn negative_value() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 3);
+        pn.step_down(&2, 5);
+        assert_eq!(-2, pn.value());
+
+        pn.step_up(&1, 4);
+        assert_eq!(2, pn.value());
+        assert_eq!(2, pn.unsigned_value());
+    }
+
+    #[test]
+    #[should_panic(expected = "the value of the counter is negative")]
+    fn unsigned_value_panics() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_down(&1, 1);
+        pn.unsigned_value();
+    }
+
+    #[test]
+    fn clamped_value() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 5);
+        pn.step_down(&2, 3);
+
+        assert_eq!(2, pn.signed_value());
+        assert_eq!(2, pn.clamped_value());
+        assert_eq!(Ok(()), pn.validate_value());
+    }
+
+    #[test]
+    fn update_actor() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 5);
+        pn.step_down(&1, 2);
+
+        assert_eq!((10, 2), pn.update_actor(&1, |p, n| (p * 2, n)));
+        assert_eq!((0, 3), pn.update_actor(&2, |p, n| (p, n + 3)));
+        assert_eq!(5, pn.value());
+    }
+
+    #[test]
+    fn remove_actor() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 5);
+        pn.step_down(&1, 2);
+        pn.step_down(&2, 1);
+
+        assert_eq!(Some((5, 2)), pn.remove_actor(&1));
+        assert_eq!(Some((0, 1)), pn.remove_actor(&2));
+        assert_eq!(None, pn.remove_actor(&1));
+        assert!(pn.is_balanced());
+    }
+
+    #[test]
+    fn sign() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        assert!(pn.is_balanced());
+        assert!(!pn.is_positive() && !pn.is_negative());
+
+        pn.step_down(&1, 2);
+        assert!(pn.is_negative());
+
+        pn.step_up(&2, 2);
+        assert!(pn.is_balanced());
+
+        pn.incr_assign(&2);
+        assert!(pn.is_positive());
+    }
+
+    #[test]
+    fn add() {
+        let mut pn1 = PNCounter::<i8, usize>::new();
+        pn1.step_up(&1, 5);
+        pn1.step_down(&2, 1);
+
+        let mut pn2 = PNCounter::<i8, usize>::new();
+        pn2.step_up(&1, 3);
+        pn2.step_down(&2, 2);
+
+        let mut merged = pn1.clone();
+        merged.merge(pn2.clone());
+
+        let mut accumulated = pn1.clone();
+        accumulated += pn2.clone();
+
+        assert!(accumulated == merged);
+        assert!(pn1 + pn2 == merged);
+        assert_eq!(3, merged.signed_value());
+    }
+
+    #[test]
+    fn delta_since() {
+        let mut checkpoint = PNCounter::<i8, usize>::new();
+        checkpoint.step_up(&1, 3);
+        checkpoint.step_down(&2, 1);
+
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 5);
+        pn.step_down(&2, 1);
+        pn.step_down(&3, 2);
+
+        let delta = pn.delta_since(&checkpoint);
+        assert_eq!(5, delta.positive_value());
+        assert_eq!(2, delta.negative_value());
+
+        checkpoint.apply_delta(delta);
+        assert_eq!(5, checkpoint.positive_value());
+        assert_eq!(3, checkpoint.negative_value());
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 5);
+        pn.step_down(&2, 3);
+
+        let restored = PNCounter::restore(pn.snapshot()).unwrap();
+        assert_eq!(5, restored.positive_value());
+        assert_eq!(3, restored.negative_value());
+
+        let restored = PNCounter::<i8, usize>::restore((vec![(1, 5)], vec![(2, 1), (2, 3)]).into());
+        assert!(matches!(restored, Err(SnapshotError::DuplicateActor)));
+
+        let mut snapshot = pn.snapshot();
+        snapshot.version = SNAPSHOT_VERSION + 1;
+        assert!(matches!(
+            PNCounter::restore(snapshot),
+            Err(SnapshotError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn clone() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 5);
+        let mut cloned = pn.clone();
+        assert!(cloned == pn);
+
+        cloned.step_down(&1, 2);
+        assert_eq!(0, pn.negative_value());
+        assert_eq!(2, cloned.negative_value());
+    }
+
+    #[test]
+    fn hash_map() {
+        let mut pn1 = PNCounter::<i8, usize>::new();
+        pn1.step_up(&1, 5);
+        let mut pn2 = PNCounter::<i8, usize>::new();
+        pn2.step_down(&1, 5);
+
+        let mut map = std::collections::HashMap::new();
+        map.insert(pn1, "up");
+        map.insert(pn2, "down");
+
+        let mut key = PNCounter::<i8, usize>::new();
+        key.step_up(&1, 5);
+        assert_eq!(Some(&"up"), map.get(&key));
+
+        key.step_down(&1, 5);
+        assert_eq!(None, map.get(&key));
+    }
+
+    #[test]
+    fn merge_with_policy() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 10);
+        pn.step_down(&2, 3);
+
+        let mut other = PNCounter::<i8, usize>::new();
+        other.step_up(&1, 12);
+        other.step_down(&2, 5);
+
+        let mut min = pn.clone();
+        min.merge_with_policy(other.clone(), &MinMerge);
+        assert_eq!(7, min.signed_value());
+
+        let mut trusted = pn.clone();
+        trusted.merge_with_policy(other, &ActorPriorityMerge::new([1]));
+        assert_eq!(9, trusted.signed_value());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hashmap_roundtrip() {
+        let mut pn = PNCounter::<i8, usize>::new();
+        pn.step_up(&1, 5);
+        pn.step_down(&2, 3);
+
+        let (positive, negative) = pn.to_hashmap();
+        assert_eq!(Some(&5), positive.get(&1));
+        assert_eq!(Some(&3), negative.get(&2));
+
+        assert!(PNCounter::from_hashmap(positive, negative) == pn);
+    }
+
+    #[cfg(feature = "testing")]
+    mod law_tests {
+        use super::*;
+        use crate::testing::*;
+
+        type Pairs = Vec<(i8, usize)>;
+
+        fn make(pairs: &(Pairs, Pairs)) -> PNCounter<i8, usize> {
+            let (pos, neg) = pairs;
+            let mut pn = PNCounter::new();
+            for (a, c) in pos {
+                pn.apply(PNOperation::Pos(Dot::new(*a, *c)));
+            }
+            for (a, c) in neg {
+                pn.apply(PNOperation::Neg(Dot::new(*a, *c)));
+            }
+            pn
+        }
+
+        fn make_op(op: &(bool, i8, usize)) -> PNOperation<i8, usize> {
+            match op {
+                (true, a, c) => PNOperation::Pos(Dot::new(*a, *c)),
+                (false, a, c) => PNOperation::Neg(Dot::new(*a, *c)),
+            }
+        }
+
+        #[test]
+        fn cvrdt_laws() {
+            assert_cvrdt_commutativity(make);
+            assert_cvrdt_associativity(make);
+            assert_cvrdt_idempotence(make);
+        }
+
+        #[test]
+        fn cmrdt_laws() {
+            assert_cmrdt_idempotence(make, make_op);
+        }
+    }
 }
@@ -0,0 +1,177 @@
+use core::convert::Infallible;
+use itertools::{EitherOrBoth, Itertools};
+
+use crate::{Actor, CmRDT, Counter, CvRDT, GMultiset, GMultisetOp};
+
+/// A multiset which supports both inserting and removing copies of an element.
+///
+/// The inserts and the removes are counted by two `GMultiset`s, and the count of an element
+/// is the difference between them, which can be negative.
+pub struct PNMultiset<A: Actor, C: Counter, E: Ord> {
+    inserts: GMultiset<A, C, E>,
+    removes: GMultiset<A, C, E>,
+}
+
+/// The operations supported by a `PNMultiset`.
+#[derive(Clone)]
+pub enum PNMultisetOp<A: Actor, C: Counter, E: Ord> {
+    /// Inserts a copy of an element.
+    Insert(GMultisetOp<A, C, E>),
+    /// Removes a copy of an element.
+    Remove(GMultisetOp<A, C, E>),
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, E: Ord> Default for PNMultiset<A, C, E> {
+    fn default() -> Self {
+        Self {
+            inserts: Default::default(),
+            removes: Default::default(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter, E: Ord> CvRDT for PNMultiset<A, C, E> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.inserts.merge(other.inserts);
+        self.removes.merge(other.removes);
+    }
+}
+
+impl<A: Actor, C: Counter, E: Ord> CmRDT for PNMultiset<A, C, E> {
+    type Op = PNMultisetOp<A, C, E>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        match op {
+            PNMultisetOp::Insert(op) => self.inserts.apply(op),
+            PNMultisetOp::Remove(op) => self.removes.apply(op),
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, E: Ord> PNMultiset<A, C, E> {
+    /// Constructs a new `PNMultiset` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which inserts a copy of an element on behalf of an actor.
+    pub fn insert(&self, actor: &A, element: E) -> PNMultisetOp<A, C, E> {
+        PNMultisetOp::Insert(self.inserts.insert(actor, element))
+    }
+
+    /// Returns the operation which removes a copy of an element on behalf of an actor.
+    pub fn remove(&self, actor: &A, element: E) -> PNMultisetOp<A, C, E> {
+        PNMultisetOp::Remove(self.removes.insert(actor, element))
+    }
+}
+
+impl<A: Actor, C: Counter + TryInto<i64>, E: Ord> PNMultiset<A, C, E> {
+    /// Returns the number of copies of a given element, the inserts minus the removes.
+    pub fn count(&self, element: &E) -> i64 {
+        Self::signed(self.inserts.count(element), self.removes.count(element))
+    }
+
+    /// Returns an iterator over the elements with a non-zero count, in ascending order.
+    pub fn elements(&self) -> impl Iterator<Item = (&E, i64)> + '_ {
+        self.inserts
+            .iter()
+            .merge_join_by(self.removes.iter(), |(e1, _), (e2, _)| e1.cmp(e2))
+            .map(|pair| match pair {
+                EitherOrBoth::Both((e, inserts), (_, removes)) => {
+                    (e, Self::signed(inserts, removes))
+                }
+                EitherOrBoth::Left((e, inserts)) => (e, Self::signed(inserts, C::zero())),
+                EitherOrBoth::Right((e, removes)) => (e, Self::signed(C::zero(), removes)),
+            })
+            .filter(|(_, count)| *count != 0)
+    }
+
+    /// Returns the inserts minus the removes, saturating counts which do not fit in an `i64`.
+    fn signed(inserts: C, removes: C) -> i64 {
+        let inserts = inserts.try_into().unwrap_or(i64::MAX);
+        let removes = removes.try_into().unwrap_or(i64::MAX);
+        inserts.saturating_sub(removes)
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let set: PNMultiset<i8, usize, &str> = PNMultiset::default();
+        assert_eq!(0, set.elements().count());
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut set: PNMultiset<i8, usize, &str> = PNMultiset::new();
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.insert(&1, "b"));
+        set.apply(set.remove(&2, "a"));
+        set.apply(set.remove(&2, "b"));
+        set.apply(set.remove(&2, "c"));
+
+        assert_eq!(1, set.count(&"a"));
+        assert_eq!(0, set.count(&"b"));
+        assert_eq!(-1, set.count(&"c"));
+        assert_eq!(
+            vec![(&"a", 1), (&"c", -1)],
+            set.elements().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_u64_counter() {
+        let mut set: PNMultiset<i8, u64, &str> = PNMultiset::new();
+        set.apply(set.insert(&1, "a"));
+        set.apply(set.remove(&2, "a"));
+        set.apply(set.remove(&2, "b"));
+
+        assert_eq!(0, set.count(&"a"));
+        assert_eq!(vec![(&"b", -1)], set.elements().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut set1: PNMultiset<i8, usize, &str> = PNMultiset::new();
+        set1.apply(set1.insert(&1, "a"));
+        set1.apply(set1.insert(&1, "a"));
+
+        let mut set2: PNMultiset<i8, usize, &str> = PNMultiset::new();
+        set2.apply(set2.insert(&1, "a"));
+        set2.apply(set2.remove(&2, "a"));
+
+        set1.merge(set2);
+        assert_eq!(1, set1.count(&"a"));
+    }
+}
@@ -0,0 +1,300 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, VClock};
+
+/// A replicated growable array, a sequence where every element is identified by a `dot`.
+///
+/// The counter of a new dot is greater than every counter observed by the sequence, so the
+/// dots act as Lamport timestamps. Concurrent inserts after the same element are ordered by
+/// descending dot, comparing the counters first and the actors next. A remove only marks the
+/// element as a tombstone, so it can still be used as a predecessor.
+pub struct Rga<A: Actor, C: Counter, T> {
+    clock: VClock<A, C>,
+    elements: Vec<RgaElement<A, C, T>>,
+}
+
+struct RgaElement<A: Actor, C: Counter, T> {
+    id: Dot<A, C>,
+    value: T,
+    removed: bool,
+}
+
+/// The operations supported by a `Rga`.
+#[derive(Clone)]
+pub enum RgaOp<A: Actor, C: Counter, T> {
+    /// Inserts a value after a given element, or at the front of the sequence.
+    Insert {
+        /// The dot which identifies the new element.
+        id: Dot<A, C>,
+        /// The element after which the value is inserted, or `None` for the front.
+        predecessor: Option<Dot<A, C>>,
+        /// The inserted value.
+        value: T,
+    },
+    /// Removes an element by turning it into a tombstone.
+    Remove {
+        /// The dot which identifies the removed element.
+        id: Dot<A, C>,
+    },
+}
+
+/// The error returned when an operation refers to an element which has not been inserted.
+#[derive(Debug, PartialEq)]
+pub enum RgaError {
+    /// The predecessor of an insert is not in the sequence.
+    UnknownPredecessor,
+    /// The removed element is not in the sequence.
+    UnknownElement,
+}
+
+impl Display for RgaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RgaError::UnknownPredecessor => write!(f, "the predecessor is not in the sequence"),
+            RgaError::UnknownElement => write!(f, "the element is not in the sequence"),
+        }
+    }
+}
+
+impl core::error::Error for RgaError {}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter, T> Default for Rga<A, C, T> {
+    fn default() -> Self {
+        Self {
+            clock: Default::default(),
+            elements: Vec::new(),
+        }
+    }
+}
+
+//
+// CmRDT
+//
+
+impl<A: Actor, C: Counter, T> CmRDT for Rga<A, C, T> {
+    type Op = RgaOp<A, C, T>;
+    type Validation = RgaError;
+
+    fn validate_op(&self, op: &Self::Op) -> Result<(), Self::Validation> {
+        match op {
+            RgaOp::Insert {
+                predecessor: Some(predecessor),
+                ..
+            } if self.position(predecessor).is_none() => Err(RgaError::UnknownPredecessor),
+            RgaOp::Remove { id } if self.position(id).is_none() => Err(RgaError::UnknownElement),
+            _ => Ok(()),
+        }
+    }
+
+    /// Applies an operation. Operations which fail `validate_op`, and inserts which have
+    /// already been applied, are ignored.
+    fn apply(&mut self, op: Self::Op) {
+        match op {
+            RgaOp::Insert {
+                id,
+                predecessor,
+                value,
+            } => {
+                if self.position(&id).is_some() {
+                    return;
+                }
+
+                let mut index = match predecessor {
+                    None => 0,
+                    Some(predecessor) => match self.position(&predecessor) {
+                        Some(index) => index + 1,
+                        None => return,
+                    },
+                };
+
+                while index < self.elements.len() && Self::is_newer(&self.elements[index].id, &id) {
+                    index += 1;
+                }
+
+                self.clock.merge(id.clone().into());
+                self.elements.insert(
+                    index,
+                    RgaElement {
+                        id,
+                        value,
+                        removed: false,
+                    },
+                );
+            }
+            RgaOp::Remove { id } => {
+                if let Some(index) = self.position(&id) {
+                    self.elements[index].removed = true;
+                }
+            }
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter, T> Rga<A, C, T> {
+    /// Constructs a new `Rga` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which inserts a value on behalf of an actor after a given
+    /// element, or at the front of the sequence.
+    pub fn insert_after(
+        &self,
+        predecessor: Option<Dot<A, C>>,
+        value: T,
+        actor: &A,
+    ) -> RgaOp<A, C, T> {
+        let counter = self.clock.max_counter().unwrap_or_else(C::zero).incr();
+        RgaOp::Insert {
+            id: Dot::new(actor.clone(), counter),
+            predecessor,
+            value,
+        }
+    }
+
+    /// Returns the operation which removes a given element.
+    pub fn remove(&self, id: Dot<A, C>) -> RgaOp<A, C, T> {
+        RgaOp::Remove { id }
+    }
+
+    /// Returns `true` if the sequence contains no live elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of live elements in the sequence.
+    pub fn len(&self) -> usize {
+        self.elements.iter().filter(|e| !e.removed).count()
+    }
+
+    /// Returns the dot which identifies the live element at a given index.
+    pub fn id_at(&self, index: usize) -> Option<Dot<A, C>> {
+        self.elements
+            .iter()
+            .filter(|e| !e.removed)
+            .nth(index)
+            .map(|e| e.id.clone())
+    }
+
+    /// Returns an iterator over the live values, in sequence order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.elements
+            .iter()
+            .filter(|e| !e.removed)
+            .map(|e| &e.value)
+    }
+
+    fn position(&self, id: &Dot<A, C>) -> Option<usize> {
+        self.elements.iter().position(|e| e.id == *id)
+    }
+
+    /// Determines if a dot orders before another one among concurrent inserts.
+    fn is_newer(dot: &Dot<A, C>, other: &Dot<A, C>) -> bool {
+        dot.counter > other.counter || (dot.counter == other.counter && dot.actor > other.actor)
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(rga: &Rga<i8, usize, char>) -> String {
+        rga.iter().collect()
+    }
+
+    #[test]
+    fn test_default() {
+        let rga: Rga<i8, usize, char> = Rga::default();
+        assert!(rga.is_empty());
+        assert_eq!(0, rga.iter().count());
+    }
+
+    #[test]
+    fn test_insert_after() {
+        let mut rga: Rga<i8, usize, char> = Rga::new();
+        rga.apply(rga.insert_after(None, 'a', &1));
+        rga.apply(rga.insert_after(rga.id_at(0), 'c', &1));
+        rga.apply(rga.insert_after(rga.id_at(0), 'b', &1));
+        rga.apply(rga.insert_after(None, '_', &1));
+
+        assert_eq!("_abc", values(&rga));
+        assert_eq!(4, rga.len());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut rga: Rga<i8, usize, char> = Rga::new();
+        rga.apply(rga.insert_after(None, 'a', &1));
+        rga.apply(rga.insert_after(rga.id_at(0), 'b', &1));
+        let a = rga.id_at(0).unwrap();
+        rga.apply(rga.remove(a));
+
+        assert_eq!("b", values(&rga));
+
+        rga.apply(rga.insert_after(Some(a), 'c', &1));
+        assert_eq!("cb", values(&rga));
+    }
+
+    #[test]
+    fn test_validate_op() {
+        let rga: Rga<i8, usize, char> = Rga::new();
+        let unknown = Dot::new(1, 1);
+
+        assert_eq!(
+            Err(RgaError::UnknownPredecessor),
+            rga.validate_op(&rga.insert_after(Some(unknown), 'a', &1))
+        );
+        assert_eq!(
+            Err(RgaError::UnknownElement),
+            rga.validate_op(&rga.remove(unknown))
+        );
+        assert_eq!(Ok(()), rga.validate_op(&rga.insert_after(None, 'a', &1)));
+    }
+
+    #[test]
+    fn test_concurrent_inserts_converge() {
+        let mut rga1: Rga<i8, usize, char> = Rga::new();
+        let mut rga2: Rga<i8, usize, char> = Rga::new();
+
+        let a = rga1.insert_after(None, 'a', &1);
+        rga2.apply(a.clone());
+        rga1.apply(a);
+
+        let x = rga1.insert_after(rga1.id_at(0), 'x', &1);
+        let y = rga2.insert_after(rga2.id_at(0), 'y', &2);
+        rga1.apply(x.clone());
+        rga1.apply(y.clone());
+        rga2.apply(y);
+        rga2.apply(x);
+
+        assert_eq!("ayx", values(&rga1));
+        assert_eq!("ayx", values(&rga2));
+    }
+
+    #[test]
+    fn test_apply_idempotent() {
+        let mut rga: Rga<i8, usize, char> = Rga::new();
+        rga.apply(rga.insert_after(None, 'a', &1));
+        let id = rga.id_at(0).unwrap();
+        rga.apply(RgaOp::Insert {
+            id,
+            predecessor: None,
+            value: 'b',
+        });
+
+        assert_eq!("a", values(&rga));
+    }
+}
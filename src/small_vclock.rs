@@ -0,0 +1,553 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::Infallible;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+
+use crate::compact::COMPACT_VERSION;
+use crate::vclock::causal_cmp;
+use crate::{
+    Actor, Causality, CausalityOrd, CmRDT, Counter, CvRDT, DecodeError, Dot, VClock, VarInt,
+};
+
+/// A vclock which keeps up to `N` dots inline and moves them to the heap once it grows beyond.
+///
+/// For the common case of a handful of actors this avoids the allocations of the `BTreeMap`
+/// behind `VClock`. The dots are kept sorted by actor in both representations.
+///
+/// The inline storage is a fixed-size array, so the actors of a `SmallVClock` must be `Copy`.
+pub struct SmallVClock<A: Actor + Copy, C: Counter, const N: usize> {
+    dots: Storage<A, C, N>,
+}
+
+enum Storage<A: Actor + Copy, C: Counter, const N: usize> {
+    Inline { len: usize, pairs: [(A, C); N] },
+    Heap(Vec<(A, C)>),
+}
+
+//
+// Constructors
+//
+
+impl<A: Actor + Copy, C: Counter, const N: usize> Default for SmallVClock<A, C, N> {
+    fn default() -> Self {
+        Self {
+            dots: Storage::Inline {
+                len: 0,
+                pairs: [(A::zero(), C::zero()); N],
+            },
+        }
+    }
+}
+
+impl<A: Actor + Copy, C: Counter, const N: usize> From<Vec<(A, C)>> for SmallVClock<A, C, N> {
+    fn from(pairs: Vec<(A, C)>) -> Self {
+        VClock::from(pairs).into()
+    }
+}
+
+/// Collects dots into a clock. When several dots belong to the same actor, the clock keeps
+/// the greatest counter.
+impl<A: Actor + Copy, C: Counter, const N: usize> FromIterator<Dot<A, C>> for SmallVClock<A, C, N> {
+    fn from_iter<I: IntoIterator<Item = Dot<A, C>>>(iter: I) -> Self {
+        let mut clock = Self::default();
+        for dot in iter {
+            clock.apply_dot(dot.actor, dot.counter);
+        }
+        clock
+    }
+}
+
+impl<A: Actor + Copy, C: Counter, const N: usize> From<VClock<A, C>> for SmallVClock<A, C, N> {
+    fn from(clock: VClock<A, C>) -> Self {
+        Self::from_sorted(clock.into_actor_counters().collect())
+    }
+}
+
+impl<A: Actor + Copy, C: Counter, const N: usize> From<SmallVClock<A, C, N>> for VClock<A, C> {
+    fn from(clock: SmallVClock<A, C, N>) -> Self {
+        clock.iter().collect()
+    }
+}
+
+impl<A: Actor + Copy, C: Counter, const N: usize> Clone for SmallVClock<A, C, N> {
+    fn clone(&self) -> Self {
+        let dots = match &self.dots {
+            Storage::Inline { len, pairs } => Storage::Inline {
+                len: *len,
+                pairs: *pairs,
+            },
+            Storage::Heap(pairs) => Storage::Heap(pairs.clone()),
+        };
+        Self { dots }
+    }
+}
+
+//
+// Formatting traits
+//
+
+impl<A: Actor + Copy + Display, C: Counter + Display, const N: usize> Display
+    for SmallVClock<A, C, N>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "<")?;
+        for (i, (a, c)) in self.pairs().iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", a, c)?;
+        }
+        write!(f, ">")
+    }
+}
+
+//
+// Order traits
+//
+
+/// Two clocks are equal when every actor has the same counter in both, the same as `VClock`.
+impl<A: Actor + Copy, C: Counter, const N: usize> PartialEq for SmallVClock<A, C, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl<A: Actor + Copy + Eq, C: Counter + Eq, const N: usize> Eq for SmallVClock<A, C, N> {}
+
+impl<A: Actor + Copy, C: Counter, const N: usize> PartialOrd for SmallVClock<A, C, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        causal_cmp(
+            self.pairs().iter().map(|(a, c)| (a, c)),
+            other.pairs().iter().map(|(a, c)| (a, c)),
+        )
+    }
+}
+
+impl<A: Actor + Copy, C: Counter, const N: usize> CausalityOrd for SmallVClock<A, C, N> {}
+
+//
+// Hashing
+//
+
+/// Feeds the non-zero actor and counter pairs in ascending actor order, the same as `VClock`.
+impl<A: Actor + Copy + Hash, C: Counter + Hash, const N: usize> Hash for SmallVClock<A, C, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        let nonzero = || self.pairs().iter().filter(|(_, c)| *c != C::zero());
+        nonzero().count().hash(state);
+        for (a, c) in nonzero() {
+            a.hash(state);
+            c.hash(state);
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor + Copy, C: Counter, const N: usize> CvRDT for SmallVClock<A, C, N> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for (a, c) in other.pairs() {
+            self.apply_dot(*a, *c);
+        }
+    }
+}
+
+impl<A: Actor + Copy + Debug + Display, C: Counter + Debug + Display, const N: usize> CmRDT
+    for SmallVClock<A, C, N>
+{
+    type Op = Dot<A, C>;
+    type Validation = Infallible;
+
+    fn validate_op(&self, _: &Self::Op) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        self.apply_dot(op.actor, op.counter);
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor + Copy, C: Counter, const N: usize> SmallVClock<A, C, N> {
+    /// Constructs a new `SmallVClock` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` is the clock contains dots.
+    pub fn is_empty(&self) -> bool {
+        self.pairs().is_empty()
+    }
+
+    /// Returns the number of dots in the clock.
+    pub fn len(&self) -> usize {
+        self.pairs().len()
+    }
+
+    /// Returns `true` if the dots have been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self.dots, Storage::Heap(_))
+    }
+
+    /// Returns the dot for a given actor, or a zero dot if the actor is not known.
+    pub fn dot_or_zero(&self, actor: &A) -> Dot<A, C> {
+        Dot::new(*actor, self.counter_or_zero(actor))
+    }
+
+    /// Returns the dot for a given actor with an incremented counter.
+    pub fn dot_incremented(&self, actor: &A) -> Dot<A, C> {
+        Dot::new(*actor, self.counter_or_zero(actor).incr())
+    }
+
+    /// Determines if a dot has been observed by the clock.
+    pub fn contains_dot(&self, dot: &Dot<A, C>) -> bool {
+        self.counter_or_zero(&dot.actor) >= dot.counter
+    }
+
+    /// Determines if the clock causally precedes another clock.
+    pub fn is_dominated_by(&self, other: &Self) -> bool {
+        self.causality_cmp(other) == Causality::Precede
+    }
+
+    /// Determines if the clock causally succeeds another clock.
+    pub fn dominates(&self, other: &Self) -> bool {
+        self.causality_cmp(other) == Causality::Succeed
+    }
+
+    /// Determines if the clock is concurrent with another clock.
+    pub fn is_concurrent_with(&self, other: &Self) -> bool {
+        self.causality_cmp(other) == Causality::Concurrent
+    }
+
+    /// Returns the causal difference, a clock with the entries whose counter exceeds the one
+    /// stored for the same actor in the other clock.
+    pub fn subtract(&self, other: &Self) -> Self {
+        Self::from_sorted(
+            self.pairs()
+                .iter()
+                .filter(|(a, c)| !matches!(other.counter(a), Some(d) if d >= *c))
+                .copied()
+                .collect(),
+        )
+    }
+
+    /// Returns what this clock has seen that the other has not, and what the other has seen
+    /// that this clock has not.
+    pub fn diff(&self, other: &Self) -> (Self, Self) {
+        (self.subtract(other), other.subtract(self))
+    }
+
+    /// Returns the meet of two clocks, a clock with the actors present in both clocks and the
+    /// minimum of their counters.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::from_sorted(
+            self.pairs()
+                .iter()
+                .filter_map(|(a, c)| other.counter(a).map(|d| (*a, if *c < d { *c } else { d })))
+                .collect(),
+        )
+    }
+
+    /// Applies a batch of dots, keeping the greatest counter for each actor.
+    pub fn apply_all(&mut self, dots: impl IntoIterator<Item = Dot<A, C>>) {
+        for dot in dots {
+            self.apply_dot(dot.actor, dot.counter);
+        }
+    }
+
+    /// Removes all the actors whose counter is zero.
+    pub fn prune_zeros(&mut self) {
+        match &mut self.dots {
+            Storage::Inline { len, pairs } => {
+                let mut kept = 0;
+                for i in 0..*len {
+                    if pairs[i].1 != C::zero() {
+                        pairs[kept] = pairs[i];
+                        kept += 1;
+                    }
+                }
+                *len = kept;
+            }
+            Storage::Heap(pairs) => pairs.retain(|(_, c)| *c != C::zero()),
+        }
+    }
+
+    /// Increments in-place the counter for a specified actor.
+    pub fn incr_assign(&mut self, actor: &A) {
+        let counter = self.counter_or_zero(actor).incr();
+        self.apply_dot(*actor, counter);
+    }
+
+    /// Returns an iterator over the dots of the clock, in ascending actor order.
+    pub fn iter(&self) -> impl Iterator<Item = Dot<A, C>> + '_ {
+        self.pairs().iter().map(|(a, c)| Dot::new(*a, *c))
+    }
+
+    /// Returns an iterator over the actors of the clock, in ascending order.
+    pub fn actors(&self) -> impl Iterator<Item = &A> + '_ {
+        self.pairs().iter().map(|(a, _)| a)
+    }
+
+    /// Returns the actor and counter pairs of the clock, in ascending actor order.
+    pub fn to_vec(&self) -> Vec<(A, C)> {
+        self.pairs().to_vec()
+    }
+
+    fn pairs(&self) -> &[(A, C)] {
+        match &self.dots {
+            Storage::Inline { len, pairs } => &pairs[..*len],
+            Storage::Heap(pairs) => pairs,
+        }
+    }
+
+    /// Builds a clock from pairs sorted by actor, keeping them inline if they fit.
+    fn from_sorted(pairs: Vec<(A, C)>) -> Self {
+        if pairs.len() > N {
+            return Self {
+                dots: Storage::Heap(pairs),
+            };
+        }
+
+        let mut small = Self::default();
+        if let Storage::Inline { len, pairs: inline } = &mut small.dots {
+            inline[..pairs.len()].copy_from_slice(&pairs);
+            *len = pairs.len();
+        }
+        small
+    }
+
+    fn counter(&self, actor: &A) -> Option<C> {
+        let pairs = self.pairs();
+        pairs
+            .binary_search_by(|(a, _)| a.cmp(actor))
+            .ok()
+            .map(|i| pairs[i].1)
+    }
+
+    fn counter_or_zero(&self, actor: &A) -> C {
+        self.counter(actor).unwrap_or_else(C::zero)
+    }
+
+    fn apply_dot(&mut self, actor: A, counter: C) {
+        let index = match self.pairs().binary_search_by(|(a, _)| a.cmp(&actor)) {
+            Ok(i) => {
+                let current = match &mut self.dots {
+                    Storage::Inline { pairs, .. } => &mut pairs[i].1,
+                    Storage::Heap(pairs) => &mut pairs[i].1,
+                };
+                if *current < counter {
+                    *current = counter;
+                }
+                return;
+            }
+            Err(_) if counter <= C::zero() => return,
+            Err(i) => i,
+        };
+
+        match &mut self.dots {
+            Storage::Inline { len, pairs } if *len < N => {
+                pairs.copy_within(index..*len, index + 1);
+                pairs[index] = (actor, counter);
+                *len += 1;
+            }
+            Storage::Inline { len, pairs } => {
+                let mut heap = Vec::with_capacity(*len + 1);
+                heap.extend_from_slice(&pairs[..*len]);
+                heap.insert(index, (actor, counter));
+                self.dots = Storage::Heap(heap);
+            }
+            Storage::Heap(pairs) => pairs.insert(index, (actor, counter)),
+        }
+    }
+}
+
+//
+// Compact encoding
+//
+
+impl<A: Actor + Copy + VarInt, C: Counter + VarInt, const N: usize> SmallVClock<A, C, N> {
+    /// Returns the compact binary encoding of the clock, the same as `VClock::encode_compact`.
+    pub fn encode_compact(&self) -> Vec<u8> {
+        let mut out = vec![COMPACT_VERSION];
+        self.len().encode_varint(&mut out);
+        for (a, c) in self.pairs() {
+            a.encode_varint(&mut out);
+            c.encode_varint(&mut out);
+        }
+        out
+    }
+
+    /// Decodes a clock from its compact binary encoding.
+    pub fn decode_compact(bytes: &[u8]) -> Result<Self, DecodeError> {
+        VClock::decode_compact(bytes).map(Self::from)
+    }
+}
+
+#[cfg(feature = "base64")]
+impl<A: Actor + Copy + VarInt, C: Counter + VarInt, const N: usize> SmallVClock<A, C, N> {
+    /// Returns the compact binary encoding of the clock as URL-safe base64 text.
+    pub fn to_base64(&self) -> alloc::string::String {
+        crate::compact::to_base64(&self.encode_compact())
+    }
+
+    /// Decodes a clock from the URL-safe base64 text of its compact binary encoding.
+    pub fn from_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::decode_compact(&crate::compact::from_base64(s)?)
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_default() {
+        let clock: SmallVClock<i8, usize, 4> = SmallVClock::default();
+        assert!(clock.is_empty());
+        assert!(!clock.spilled());
+    }
+
+    #[test]
+    fn test_incr_assign() {
+        let mut clock: SmallVClock<i8, usize, 4> = SmallVClock::new();
+        clock.incr_assign(&2);
+        clock.incr_assign(&1);
+        clock.incr_assign(&2);
+
+        assert_eq!(2, clock.dot_or_zero(&2).counter);
+        assert_eq!(1, clock.dot_or_zero(&1).counter);
+        assert_eq!(0, clock.dot_or_zero(&3).counter);
+        assert_eq!(vec![(1, 1), (2, 2)], clock.to_vec());
+    }
+
+    #[test]
+    fn test_spill() {
+        let mut clock: SmallVClock<i8, usize, 2> = vec![(3, 30), (1, 10)].into();
+        assert!(!clock.spilled());
+
+        clock.incr_assign(&2);
+        assert!(clock.spilled());
+        assert_eq!(vec![(1, 10), (2, 1), (3, 30)], clock.to_vec());
+    }
+
+    #[test]
+    fn test_display() {
+        let clock: SmallVClock<i8, usize, 4> = vec![(2, 20), (1, 10)].into();
+        assert_eq!("<1:10,2:20>", clock.to_string());
+    }
+
+    #[test]
+    fn test_causality() {
+        let clock1: SmallVClock<i8, usize, 4> = vec![(1, 10), (2, 20)].into();
+        let clock2: SmallVClock<i8, usize, 4> = vec![(1, 10), (2, 30)].into();
+        let clock3: SmallVClock<i8, usize, 4> = vec![(1, 20)].into();
+
+        assert_eq!(crate::Causality::Precede, clock1.causality_cmp(&clock2));
+        assert_eq!(crate::Causality::Succeed, clock2.causality_cmp(&clock1));
+        assert_eq!(crate::Causality::Concurrent, clock1.causality_cmp(&clock3));
+        assert_eq!(
+            crate::Causality::Equal,
+            clock1.causality_cmp(&clock1.clone())
+        );
+    }
+
+    #[quickcheck]
+    fn test_matches_vclock(pairs: Vec<(i8, usize)>, others: Vec<(i8, usize)>) -> bool {
+        let mut small: SmallVClock<i8, usize, 4> = pairs.clone().into();
+        let mut clock: VClock<i8, usize> = pairs.into();
+
+        small.merge(others.clone().into());
+        clock.merge(others.into());
+
+        small.to_vec() == clock.to_vec() && VClock::from(small) == clock
+    }
+
+    #[test]
+    fn test_eq_zero_entries() {
+        let mut clock: SmallVClock<i8, usize, 4> = SmallVClock::new();
+        clock.apply_dot(5, 1);
+        if let Storage::Inline { pairs, .. } = &mut clock.dots {
+            pairs[0].1 = 0;
+        }
+
+        assert!(clock == SmallVClock::default());
+        assert_eq!(
+            Some(Ordering::Equal),
+            clock.partial_cmp(&SmallVClock::default())
+        );
+
+        clock.prune_zeros();
+        assert!(clock.is_empty());
+    }
+
+    #[quickcheck]
+    fn test_cmp_matches_vclock(pairs: Vec<(i8, usize)>, others: Vec<(i8, usize)>) -> bool {
+        let small: SmallVClock<i8, usize, 4> = pairs.into();
+        let other: SmallVClock<i8, usize, 4> = others.into();
+
+        let expected = VClock::from(small.clone()).partial_cmp(&VClock::from(other.clone()));
+        small.partial_cmp(&other) == expected
+            && (small == other) == (expected == Some(Ordering::Equal))
+    }
+
+    #[quickcheck]
+    fn test_operations_match_vclock(pairs: Vec<(i8, usize)>, others: Vec<(i8, usize)>) -> bool {
+        let small: SmallVClock<i8, usize, 4> = pairs.clone().into();
+        let other: SmallVClock<i8, usize, 4> = others.clone().into();
+        let clock: VClock<i8, usize> = pairs.into();
+        let clock_other: VClock<i8, usize> = others.into();
+
+        let (small_has, other_has) = small.diff(&other);
+        let (clock_has, clock_other_has) = clock.diff(&clock_other);
+
+        small.subtract(&other).to_vec() == clock.subtract(&clock_other).to_vec()
+            && small.intersection(&other).to_vec() == clock.intersection(&clock_other).to_vec()
+            && small_has.to_vec() == clock_has.to_vec()
+            && other_has.to_vec() == clock_other_has.to_vec()
+    }
+
+    #[quickcheck]
+    fn test_apply_all(pairs: Vec<(i8, usize)>, dots: Vec<(i8, usize)>) -> bool {
+        let mut small: SmallVClock<i8, usize, 4> = pairs.clone().into();
+        let mut clock: VClock<i8, usize> = pairs.into();
+
+        small.apply_all(dots.iter().map(|d| Dot::from(*d)));
+        clock.apply_all(dots.into_iter().map(Dot::from));
+        small.to_vec() == clock.to_vec()
+    }
+
+    #[quickcheck]
+    fn test_compact_roundtrip(pairs: Vec<(i8, u64)>) -> bool {
+        let small: SmallVClock<i8, u64, 4> = pairs.clone().into();
+        let clock: VClock<i8, u64> = pairs.into();
+
+        small.encode_compact() == clock.encode_compact()
+            && SmallVClock::<i8, u64, 4>::decode_compact(&small.encode_compact())
+                .is_ok_and(|c| c == small)
+    }
+
+    #[quickcheck]
+    fn test_from_vclock(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        let small: SmallVClock<i8, usize, 4> = clock.clone().into();
+
+        small.spilled() == (clock.len() > 4) && small.to_vec() == clock.to_vec()
+    }
+}
@@ -0,0 +1,114 @@
+//! Helpers which check the algebraic laws of CRDT's against randomly generated states.
+//!
+//! The states and operations are rebuilt from `quickcheck` generated seeds, so the CRDT's
+//! under test do not need to be `Clone`. Each helper panics with the offending seeds when
+//! the law does not hold.
+
+use core::fmt::Debug;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{CmRDT, CvRDT};
+
+const TESTS: usize = 100;
+const SIZE: usize = 20;
+
+fn merged<S, T: CvRDT>(make: &impl Fn(&S) -> T, a: &S, b: &S) -> T {
+    let mut t = make(a);
+    t.merge(make(b));
+    t
+}
+
+/// Checks that merging `a` into `b` gives the same state as merging `b` into `a`.
+pub fn assert_cvrdt_commutativity<S, T>(make: impl Fn(&S) -> T)
+where
+    S: Arbitrary + Debug,
+    T: CvRDT + PartialEq,
+{
+    let mut g = Gen::new(SIZE);
+    for _ in 0..TESTS {
+        let a = S::arbitrary(&mut g);
+        let b = S::arbitrary(&mut g);
+
+        assert!(
+            merged(&make, &a, &b) == merged(&make, &b, &a),
+            "merge is not commutative for {:?} and {:?}",
+            a,
+            b
+        );
+    }
+}
+
+/// Checks that the grouping of the merges of `a`, `b` and `c` does not change the state.
+pub fn assert_cvrdt_associativity<S, T>(make: impl Fn(&S) -> T)
+where
+    S: Arbitrary + Debug,
+    T: CvRDT + PartialEq,
+{
+    let mut g = Gen::new(SIZE);
+    for _ in 0..TESTS {
+        let a = S::arbitrary(&mut g);
+        let b = S::arbitrary(&mut g);
+        let c = S::arbitrary(&mut g);
+
+        let mut left = merged(&make, &a, &b);
+        left.merge(make(&c));
+
+        let mut right = make(&a);
+        right.merge(merged(&make, &b, &c));
+
+        assert!(
+            left == right,
+            "merge is not associative for {:?}, {:?} and {:?}",
+            a,
+            b,
+            c
+        );
+    }
+}
+
+/// Checks that merging a state with itself does not change the state.
+pub fn assert_cvrdt_idempotence<S, T>(make: impl Fn(&S) -> T)
+where
+    S: Arbitrary + Debug,
+    T: CvRDT + PartialEq,
+{
+    let mut g = Gen::new(SIZE);
+    for _ in 0..TESTS {
+        let a = S::arbitrary(&mut g);
+
+        assert!(
+            merged(&make, &a, &a) == make(&a),
+            "merge is not idempotent for {:?}",
+            a
+        );
+    }
+}
+
+/// Checks that applying an operation twice gives the same state as applying it once.
+pub fn assert_cmrdt_idempotence<S, O, T>(make: impl Fn(&S) -> T, make_op: impl Fn(&O) -> T::Op)
+where
+    S: Arbitrary + Debug,
+    O: Arbitrary + Debug,
+    T: CmRDT + PartialEq,
+{
+    let mut g = Gen::new(SIZE);
+    for _ in 0..TESTS {
+        let a = S::arbitrary(&mut g);
+        let op = O::arbitrary(&mut g);
+
+        let mut once = make(&a);
+        once.apply(make_op(&op));
+
+        let mut twice = make(&a);
+        twice.apply(make_op(&op));
+        twice.apply(make_op(&op));
+
+        assert!(
+            once == twice,
+            "applying {:?} is not idempotent for {:?}",
+            op,
+            a
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use core::convert::Infallible;
+use core::fmt::Display;
+
+use crate::{CmRDT, CvRDT, GSet};
+
+/// A two-phase set, where a removed element can never be inserted again.
+pub struct TwoPSet<E: Ord> {
+    adds: GSet<E>,
+    removes: GSet<E>,
+}
+
+/// The operations supported by a `TwoPSet`.
+pub enum TwoPSetOp<E: Ord> {
+    /// Inserts an element in the set.
+    Insert(E),
+    /// Removes an element from the set.
+    Remove(E),
+}
+
+/// The error returned when inserting an element which has already been removed.
+#[derive(Debug, PartialEq)]
+pub struct RemovedElement;
+
+impl Display for RemovedElement {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the element has already been removed")
+    }
+}
+
+impl core::error::Error for RemovedElement {}
+
+//
+// Constructors
+//
+
+impl<E: Ord> Default for TwoPSet<E> {
+    fn default() -> Self {
+        Self {
+            adds: Default::default(),
+            removes: Default::default(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<E: Ord> CvRDT for TwoPSet<E> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.adds.merge(other.adds);
+        self.removes.merge(other.removes);
+    }
+}
+
+impl<E: Ord> CmRDT for TwoPSet<E> {
+    type Op = TwoPSetOp<E>;
+    type Validation = RemovedElement;
+
+    fn validate_op(&self, op: &Self::Op) -> Result<(), Self::Validation> {
+        match op {
+            TwoPSetOp::Insert(element) if self.removes.contains(element) => Err(RemovedElement),
+            _ => Ok(()),
+        }
+    }
+
+    fn apply(&mut self, op: Self::Op) {
+        match op {
+            TwoPSetOp::Insert(element) => self.adds.apply(element),
+            TwoPSetOp::Remove(element) => self.removes.apply(element),
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<E: Ord> TwoPSet<E> {
+    /// Constructs a new `TwoPSet` instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operation which inserts an element into the set.
+    pub fn insert(&self, element: E) -> TwoPSetOp<E> {
+        TwoPSetOp::Insert(element)
+    }
+
+    /// Returns the operation which removes an element from the set.
+    pub fn remove(&self, element: E) -> TwoPSetOp<E> {
+        TwoPSetOp::Remove(element)
+    }
+
+    /// Determines if the set contains a given element.
+    pub fn contains(&self, element: &E) -> bool {
+        self.adds.contains(element) && !self.removes.contains(element)
+    }
+
+    /// Returns an iterator over the live elements of the set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &E> + '_ {
+        self.adds.iter().filter(|e| !self.removes.contains(e))
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn test_insert(element: i8) -> bool {
+        let mut set = TwoPSet::new();
+        set.apply(set.insert(element));
+        set.contains(&element)
+    }
+
+    #[quickcheck]
+    fn test_remove(element: i8) -> bool {
+        let mut set = TwoPSet::new();
+        set.apply(set.insert(element));
+        set.apply(set.remove(element));
+        !set.contains(&element)
+    }
+
+    #[quickcheck]
+    fn test_reinsert(element: i8) -> bool {
+        let mut set = TwoPSet::new();
+        set.apply(set.insert(element));
+        set.apply(set.remove(element));
+
+        let op = set.insert(element);
+        let validation = set.validate_op(&op);
+        set.apply(op);
+
+        validation == Err(RemovedElement) && !set.contains(&element)
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut set1 = TwoPSet::new();
+        set1.apply(set1.insert(1));
+        set1.apply(set1.insert(2));
+
+        let mut set2 = TwoPSet::new();
+        set2.apply(set2.insert(3));
+        set2.apply(set2.remove(1));
+
+        set1.merge(set2);
+        assert_eq!(vec![&2, &3], set1.iter().collect::<Vec<_>>());
+    }
+}
@@ -1,5 +1,5 @@
-use std::error::Error;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use core::error::Error;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 
 /// Represents the identity value `zero`.
 pub trait Zero {
@@ -38,8 +38,43 @@ pub trait Counter:
     }
 }
 
+/// The overflow-checked arithmetic of a counter, implemented by the integer counters and their
+/// `Saturating`, `Wrapping` and `CheckedCounter` wrappers.
+pub trait CheckedCounterOps: Counter {
+    /// Adds two counters, returning `None` on overflow.
+    fn checked_add(&self, rhs: Self) -> Option<Self>;
+
+    /// Subtracts two counters, returning `None` on overflow.
+    fn checked_sub(&self, rhs: Self) -> Option<Self>;
+
+    /// Returns a counter with an incremented value, or `None` on overflow.
+    fn checked_incr(&self) -> Option<Self> {
+        self.checked_add(Self::one())
+    }
+
+    /// Increments in-place the counter. On overflow the counter is left unchanged and the
+    /// method returns `false`.
+    fn checked_incr_assign(&mut self) -> bool {
+        self.checked_incr().map(|c| *self = c).is_some()
+    }
+
+    /// Returns a counter with a decremented value, or `None` on overflow.
+    fn checked_decr(&self) -> Option<Self> {
+        self.checked_sub(Self::one())
+    }
+
+    /// Decrements in-place the counter. On overflow the counter is left unchanged and the
+    /// method returns `false`.
+    fn checked_decr_assign(&mut self) -> bool {
+        self.checked_decr().map(|c| *self = c).is_some()
+    }
+}
+
 /// The actor identifier.
-pub trait Actor: Copy + Ord + Zero {}
+///
+/// Actors only need to be `Clone`, so owned identities like `String` can be used directly. A
+/// `Dot` is `Copy` whenever its actor is, which keeps the primitive actors cheap to pass around.
+pub trait Actor: Clone + Ord + Zero {}
 
 /// The set assignement operation.
 pub trait UpdateAssign<Rhs = Self> {
@@ -61,6 +96,18 @@ pub enum Causality {
     Concurrent,
 }
 
+impl core::fmt::Display for Causality {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let relation = match self {
+            Causality::Precede => "precedes",
+            Causality::Equal => "equals",
+            Causality::Succeed => "succeeds",
+            Causality::Concurrent => "concurrent",
+        };
+        write!(f, "{}", relation)
+    }
+}
+
 /// A trait that compares two events and returns their causality relation.
 pub trait CausalityOrd: PartialOrd {
     /// Returns the causality relation between two entities.
@@ -86,6 +133,54 @@ pub trait CvRDT {
     fn merge(&mut self, other: Self);
 }
 
+/// Delta-state CRDT's replicate by transmitting only the recently changed part of the state.
+pub trait DeltaCRDT: CvRDT + Sized {
+    /// Returns the minimal delta which brings the checkpoint up to date with the current CRDT.
+    fn delta_since(&self, checkpoint: &Self) -> Self;
+
+    /// Applies a delta returned by `delta_since`, which is the same as merging it.
+    fn apply_delta(&mut self, delta: Self) {
+        self.merge(delta)
+    }
+}
+
+/// The error returned when a snapshot cannot be restored.
+#[derive(Debug, PartialEq)]
+pub enum SnapshotError {
+    /// The snapshot contains the same actor more than once.
+    DuplicateActor,
+    /// The snapshot was written with a schema version newer than `SNAPSHOT_VERSION`.
+    UnsupportedVersion(u8),
+}
+
+impl core::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SnapshotError::DuplicateActor => write!(f, "the snapshot contains a duplicate actor"),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+        }
+    }
+}
+
+impl Error for SnapshotError {}
+
+/// The schema version written into the snapshots of the crate.
+pub const SNAPSHOT_VERSION: u8 = 1;
+
+/// Captures the state of a CRDT so it can be persisted and restored later.
+pub trait Snapshot: Sized {
+    /// The plain data which describes the state.
+    type SnapshotData;
+
+    /// Returns the data which describes the current state.
+    fn snapshot(&self) -> Self::SnapshotData;
+
+    /// Rebuilds a CRDT from the data returned by `snapshot`.
+    fn restore(data: Self::SnapshotData) -> Result<Self, SnapshotError>;
+}
+
 /// Commutative or operation based CRDT's replicate by transmitting each operation.
 pub trait CmRDT {
     /// Op's must be idempotent, meaning any Op may be applied more than once.
@@ -109,3 +204,20 @@ pub trait CRDT {
     /// Returns the value of the CRDT.
     fn value(&self) -> Self::Output;
 }
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn causality_display() {
+        assert_eq!("precedes", Causality::Precede.to_string());
+        assert_eq!("equals", Causality::Equal.to_string());
+        assert_eq!("succeeds", Causality::Succeed.to_string());
+        assert_eq!("concurrent", Causality::Concurrent.to_string());
+    }
+}
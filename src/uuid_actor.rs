@@ -0,0 +1,102 @@
+use core::fmt::Display;
+
+use uuid::Uuid;
+
+use crate::{Actor, Zero};
+
+/// An actor identified by a UUID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct UuidActor(pub Uuid);
+
+//
+// Constructors
+//
+
+impl From<Uuid> for UuidActor {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<UuidActor> for Uuid {
+    fn from(actor: UuidActor) -> Self {
+        actor.0
+    }
+}
+
+//
+// Formatting traits
+//
+
+impl Display for UuidActor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+//
+// Identities
+//
+
+impl Zero for UuidActor {
+    fn zero() -> Self {
+        Self(Uuid::nil())
+    }
+}
+
+impl Actor for UuidActor {}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dot, VClock};
+
+    fn actor(n: u128) -> UuidActor {
+        Uuid::from_u128(n).into()
+    }
+
+    #[test]
+    fn test_zero() {
+        let actor = UuidActor::zero();
+        assert!(Uuid::from(actor).is_nil());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "00000000-0000-0000-0000-000000000001",
+            format!("{}", actor(1))
+        );
+    }
+
+    #[test]
+    fn test_vclock() {
+        let mut clock: VClock<UuidActor, u64> = vec![(actor(1), 10), (actor(2), 20)].into();
+        clock.incr_assign(&actor(1));
+        clock.incr_assign(&actor(3));
+
+        assert!(clock.contains_dot(&Dot::new(actor(1), 11)));
+        assert!(clock.contains_dot(&Dot::new(actor(3), 1)));
+        assert!(!clock.contains_dot(&Dot::new(actor(2), 21)));
+        assert_eq!(3, clock.len());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&actor(1)).unwrap();
+        assert_eq!(r#""00000000-0000-0000-0000-000000000001""#, json);
+
+        let back: UuidActor = serde_json::from_str(&json).unwrap();
+        assert_eq!(actor(1), back);
+    }
+}
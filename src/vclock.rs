@@ -1,10 +1,22 @@
-use std::collections::{btree_map, BTreeMap};
-use std::convert::Infallible;
-use std::fmt::{Debug, Display};
-use std::ops::AddAssign;
-use itertools::Itertools;
-
-use crate::{Actor, CausalityOrd, CmRDT, Counter, CvRDT, Dot, DotRange, UpdateAssign};
+use alloc::collections::{btree_map, BTreeMap};
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+use core::ops::{Add, AddAssign, BitAnd, BitOr, Sub, SubAssign};
+use core::str::FromStr;
+use itertools::{EitherOrBoth, Itertools};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::compact::{decode_end, decode_version, COMPACT_VERSION};
+use crate::{
+    Actor, ActorPriorityMerge, Causality, CausalityOrd, CmRDT, Counter, CvRDT, DecodeError,
+    DeltaCRDT, Dot, DotRange, MergePolicy, MinMerge, Snapshot, SnapshotError, UpdateAssign, VarInt,
+    SNAPSHOT_VERSION,
+};
 
 /// A vclock is a collection of dots, one for each actor.
 pub struct VClock<A: Actor, C: Counter> {
@@ -42,23 +54,37 @@ impl<A: Actor, C: Counter> From<Dot<A, C>> for VClock<A, C> {
 impl<A: Actor, C: Counter> From<Vec<(A, C)>> for VClock<A, C> {
     fn from(pairs: Vec<(A, C)>) -> Self {
         let mut clock = Self::default();
-        let _: Vec<&(A, C)> = pairs
-            .iter()
-            .inspect(|(a, c)| {
-                clock.dots.insert(*a, *c);
-                ()
-            })
-            .collect();
+        clock.dots.extend(pairs);
+        clock
+    }
+}
+
+/// Collects dots into a clock. When several dots belong to the same actor, the clock keeps
+/// the greatest counter.
+impl<A: Actor, C: Counter> FromIterator<Dot<A, C>> for VClock<A, C> {
+    fn from_iter<I: IntoIterator<Item = Dot<A, C>>>(iter: I) -> Self {
+        let mut clock = Self::default();
+        for dot in iter {
+            clock.apply_dot(dot);
+        }
         clock
     }
 }
 
+impl<A: Actor, C: Counter> Clone for VClock<A, C> {
+    fn clone(&self) -> Self {
+        Self {
+            dots: self.dots.clone(),
+        }
+    }
+}
+
 //
 // Formatting traits
 //
 
 impl<A: Actor + Display, C: Counter + Display> Display for VClock<A, C> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 
         let dots = self.dots.iter().map(|(a,c)| format!("{}:{}", *a, *c)).join(",");
 
@@ -68,38 +94,195 @@ impl<A: Actor + Display, C: Counter + Display> Display for VClock<A, C> {
     }
 }
 
+//
+// Parsing
+//
+
+/// The reason a string could not be parsed into a `VClock`.
+#[derive(Debug, PartialEq)]
+pub enum ParseVClockErrorKind {
+    /// The string does not start with `<`.
+    MissingOpeningBracket,
+    /// The string does not end with `>`.
+    MissingClosingBracket,
+    /// An entry is not separated by `:` into an actor and a counter.
+    MissingColon,
+    /// The actor of an entry could not be parsed.
+    InvalidActor,
+    /// The counter of an entry could not be parsed.
+    InvalidCounter,
+}
+
+/// The error returned when parsing a `VClock` from a string.
+#[derive(Debug, PartialEq)]
+pub struct ParseVClockError {
+    /// The reason of the failure.
+    pub kind: ParseVClockErrorKind,
+    /// The byte offset in the string where the failure occurred.
+    pub position: usize,
+}
+
+impl Display for ParseVClockError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let reason = match self.kind {
+            ParseVClockErrorKind::MissingOpeningBracket => "expected '<'",
+            ParseVClockErrorKind::MissingClosingBracket => "expected '>'",
+            ParseVClockErrorKind::MissingColon => "expected ':'",
+            ParseVClockErrorKind::InvalidActor => "invalid actor",
+            ParseVClockErrorKind::InvalidCounter => "invalid counter",
+        };
+
+        write!(f, "{} at position {}", reason, self.position)
+    }
+}
+
+impl core::error::Error for ParseVClockError {}
+
+/// Parses the `<a1:c1,a2:c2>` format produced by `Display`.
+impl<A: Actor + FromStr, C: Counter + FromStr> FromStr for VClock<A, C> {
+    type Err = ParseVClockError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |kind, position| ParseVClockError { kind, position };
+
+        if !s.starts_with('<') {
+            return Err(error(ParseVClockErrorKind::MissingOpeningBracket, 0));
+        }
+
+        if s.len() < 2 || !s.ends_with('>') {
+            return Err(error(ParseVClockErrorKind::MissingClosingBracket, s.len()));
+        }
+
+        let mut clock = Self::default();
+        let inner = &s[1..s.len() - 1];
+        if inner.is_empty() {
+            return Ok(clock);
+        }
+
+        let mut position = 1;
+        for entry in inner.split(',') {
+            let colon = entry
+                .find(':')
+                .ok_or_else(|| error(ParseVClockErrorKind::MissingColon, position))?;
+
+            let actor = entry[..colon]
+                .parse()
+                .map_err(|_| error(ParseVClockErrorKind::InvalidActor, position))?;
+            let counter = entry[colon + 1..]
+                .parse()
+                .map_err(|_| error(ParseVClockErrorKind::InvalidCounter, position + colon + 1))?;
+
+            clock.dots.insert(actor, counter);
+            position += entry.len() + 1;
+        }
+
+        Ok(clock)
+    }
+}
+
 //
 // Order traits
 //
 
+/// Two clocks are equal when every actor has the same counter in both, an actor missing from
+/// a clock counting as zero.
 impl<A: Actor, C: Counter> PartialEq for VClock<A, C> {
     fn eq(&self, other: &Self) -> bool {
-        if self.dots.len() != other.dots.len() {
-            false
-        } else {
-            self.dots
-                .iter()
-                .all(|(a, c)| other.dots.get(&a).map_or(false, |d| d == c))
-        }
+        self.partial_cmp(other) == Some(core::cmp::Ordering::Equal)
     }
 }
 
+impl<A: Actor, C: Counter> Eq for VClock<A, C> {}
+
+/// The causal order, where an actor missing from a clock counts as zero.
 impl<A: Actor, C: Counter> PartialOrd for VClock<A, C> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self == other {
-            Some(core::cmp::Ordering::Equal)
-        } else if self.dots.iter().all(|(a, c)| other.contains_greater(a, c)) {
-            Some(core::cmp::Ordering::Less)
-        } else if self.dots.iter().all(|(a, c)| other.contains_less(a, c)) {
-            Some(core::cmp::Ordering::Greater)
-        } else {
-            None
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        causal_cmp(self.dots.iter(), other.dots.iter())
+    }
+}
+
+/// Compares causally two sequences of actor and counter pairs sorted by actor, in a single
+/// pass. An actor missing from one of the sequences counts as zero.
+pub(crate) fn causal_cmp<'a, A: Actor + 'a, C: Counter + 'a>(
+    left: impl Iterator<Item = (&'a A, &'a C)>,
+    right: impl Iterator<Item = (&'a A, &'a C)>,
+) -> Option<core::cmp::Ordering> {
+    let zero = C::zero();
+    let (mut le, mut ge) = (true, true);
+
+    for pair in left.merge_join_by(right, |(a1, _), (a2, _)| a1.cmp(a2)) {
+        let ordering = match pair {
+            EitherOrBoth::Both((_, c1), (_, c2)) => c1.partial_cmp(c2),
+            EitherOrBoth::Left((_, c1)) => c1.partial_cmp(&zero),
+            EitherOrBoth::Right((_, c2)) => zero.partial_cmp(c2),
+        };
+
+        match ordering? {
+            core::cmp::Ordering::Less => ge = false,
+            core::cmp::Ordering::Greater => le = false,
+            core::cmp::Ordering::Equal => {}
+        }
+
+        if !le && !ge {
+            return None;
         }
     }
+
+    match (le, ge) {
+        (true, true) => Some(core::cmp::Ordering::Equal),
+        (true, false) => Some(core::cmp::Ordering::Less),
+        (false, true) => Some(core::cmp::Ordering::Greater),
+        (false, false) => None,
+    }
 }
 
 impl<A: Actor, C: Counter> CausalityOrd for VClock<A, C> {}
 
+impl<A: Actor, C: Counter + Ord> VClock<A, C> {
+    /// Compares two clocks by their number of actors, and then lexicographically by their
+    /// `(actor, counter)` pairs in ascending actor order. Zero counters are skipped, as in
+    /// `PartialEq`. This is not the causal order.
+    pub fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.nonzero_dots()
+            .count()
+            .cmp(&other.nonzero_dots().count())
+            .then_with(|| self.nonzero_dots().cmp(other.nonzero_dots()))
+    }
+}
+
+/// A vclock ordered by `VClock::total_cmp`, for use as the key of sorted collections such as
+/// a `BTreeSet`.
+///
+/// The equality and the order of the wrapper both come from `total_cmp`, which is not the
+/// causal order of `VClock`, so it must not be used for causal reasoning.
+pub struct TotalOrdVClock<A: Actor, C: Counter>(pub VClock<A, C>);
+
+impl<A: Actor, C: Counter> From<VClock<A, C>> for TotalOrdVClock<A, C> {
+    fn from(clock: VClock<A, C>) -> Self {
+        Self(clock)
+    }
+}
+
+impl<A: Actor, C: Counter + Ord> PartialEq for TotalOrdVClock<A, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == core::cmp::Ordering::Equal
+    }
+}
+
+impl<A: Actor, C: Counter + Ord> Eq for TotalOrdVClock<A, C> {}
+
+impl<A: Actor, C: Counter + Ord> PartialOrd for TotalOrdVClock<A, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A: Actor, C: Counter + Ord> Ord for TotalOrdVClock<A, C> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
 //
 // Operations
 //
@@ -117,6 +300,77 @@ impl<A: Actor, C: Counter> UpdateAssign<Dot<A, C>> for VClock<A, C> {
     }
 }
 
+/// Merges in-place another clock, the same as `CvRDT::merge`.
+///
+/// Unlike adding a `Dot`, which steps up the counter of its actor, adding a clock keeps the
+/// greatest counter of every actor.
+impl<A: Actor, C: Counter> AddAssign for VClock<A, C> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.merge(rhs);
+    }
+}
+
+/// The merge of two clocks, the same as `|`.
+impl<A: Actor, C: Counter> Add for VClock<A, C> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+/// The join of two clocks, the same as merging them.
+impl<A: Actor, C: Counter> BitOr for VClock<A, C> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self.merge(rhs);
+        self
+    }
+}
+
+/// The meet of two clocks, the same as their intersection.
+impl<A: Actor, C: Counter> BitAnd for VClock<A, C> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(&rhs)
+    }
+}
+
+/// The causal difference of two clocks.
+impl<A: Actor, C: Counter> Sub for VClock<A, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.subtract(&rhs)
+    }
+}
+
+/// Removes in-place the entries whose counter does not exceed the one of the other clock.
+impl<A: Actor, C: Counter> SubAssign for VClock<A, C> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.dots.retain(|a, c| !rhs.contains_greater(a, c));
+    }
+}
+
+//
+// Hashing
+//
+
+/// Feeds the non-zero actor and counter pairs in ascending actor order, consistent with
+/// `PartialEq`.
+impl<A: Actor + Hash, C: Counter + Hash> Hash for VClock<A, C> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.nonzero_dots().count().hash(state);
+        for (a, c) in self.nonzero_dots() {
+            a.hash(state);
+            c.hash(state);
+        }
+    }
+}
+
 //
 // Implemenation
 //
@@ -128,7 +382,7 @@ impl<A: Actor, C: Counter> VClock<A, C> {
             dots: BTreeMap::new(),
         };
 
-        clock.dots.insert(*actor, *count);
+        clock.dots.insert(actor.clone(), *count);
         clock
     }
 
@@ -142,11 +396,23 @@ impl<A: Actor, C: Counter> VClock<A, C> {
         self.dots.len()
     }
 
+    /// Returns the number of actors whose counter is greater than zero.
+    pub fn len_nonzero(&self) -> usize {
+        self.actors_nonzero().count()
+    }
+
+    /// Returns `true` if every counter of the clock is zero, or the clock is empty.
+    pub fn is_zero(&self) -> bool {
+        self.dots.values().all(|c| *c == C::zero())
+    }
+
     /// Returns the `dot` stored for a given actor
     pub fn dot_or_zero(&self, actor: &A) -> Dot<A, C> {
         self.dots
             .get_key_value(actor)
-            .map_or(Dot::new_zero(*actor), |(a, c)| Dot::new(*a, *c))
+            .map_or(Dot::new_zero(actor.clone()), |(a, c)| {
+                Dot::new(a.clone(), *c)
+            })
     }
 
     /// Returns a dot with an incremented for a given
@@ -154,19 +420,233 @@ impl<A: Actor, C: Counter> VClock<A, C> {
         self.dot_or_zero(actor).incr()
     }
 
+    /// Determines if a given `dot` has been observed by the clock.
+    pub fn contains_dot(&self, dot: &Dot<A, C>) -> bool {
+        self.get_counter_or_zero(&dot.actor) >= dot.counter
+    }
+
+    /// Determines if the clock is concurrent with another clock.
+    pub fn is_concurrent_with(&self, other: &Self) -> bool {
+        self.causality_cmp(other) == Causality::Concurrent
+    }
+
+    /// Determines if the clock causally succeeds another clock.
+    pub fn dominates(&self, other: &Self) -> bool {
+        self.causality_cmp(other) == Causality::Succeed
+    }
+
+    /// Determines if the clock causally precedes another clock. Returns `false` for equal or
+    /// concurrent clocks.
+    pub fn is_dominated_by(&self, other: &Self) -> bool {
+        self.causality_cmp(other) == Causality::Precede
+    }
+
+    /// Returns the causal difference, a clock with the entries whose counter exceeds the one stored
+    /// for the same actor in the other clock.
+    pub fn subtract(&self, other: &Self) -> Self {
+        Self {
+            dots: self
+                .dots
+                .iter()
+                .filter(|(a, c)| !other.contains_greater(a, c))
+                .map(|(a, c)| (a.clone(), *c))
+                .collect(),
+        }
+    }
+
+    /// Returns the greatest counter across all the actors, or `None` if the clock is empty.
+    pub fn max_counter(&self) -> Option<C> {
+        self.dots.values().fold(None, |max, c| match max {
+            Some(m) if m >= *c => Some(m),
+            _ => Some(*c),
+        })
+    }
+
+    /// Returns the sum of the counters of all the actors, the total number of events.
+    pub fn sum_counters(&self) -> C {
+        self.dots.values().fold(C::zero(), |sum, c| sum + *c)
+    }
+
+    /// Folds the actor and counter pairs, in ascending actor order, into a single value.
+    pub fn fold_actors<B>(&self, init: B, f: impl Fn(B, &A, &C) -> B) -> B {
+        self.dots.iter().fold(init, |acc, (a, c)| f(acc, a, c))
+    }
+
+    /// Returns a clock with the same actors and the counters transformed by a function.
+    pub fn map_counters<D: Counter>(&self, f: impl Fn(&A, &C) -> D) -> VClock<A, D> {
+        VClock {
+            dots: self
+                .dots
+                .iter()
+                .map(|(a, c)| (a.clone(), f(a, c)))
+                .collect(),
+        }
+    }
+
+    /// Returns a clock with the actor and counter pairs which satisfy a predicate.
+    pub fn filter_actors(&self, f: impl Fn(&A, &C) -> bool) -> Self {
+        Self {
+            dots: self
+                .dots
+                .iter()
+                .filter(|(a, c)| f(a, c))
+                .map(|(a, c)| (a.clone(), *c))
+                .collect(),
+        }
+    }
+
+    /// Combines the counters of two clocks actor by actor. An actor missing from one of the
+    /// clocks has a zero counter there.
+    pub fn zip_with(&self, other: &Self, f: impl Fn(&C, &C) -> C) -> Self {
+        let zero = C::zero();
+        Self {
+            dots: self
+                .dots
+                .iter()
+                .merge_join_by(other.dots.iter(), |(a1, _), (a2, _)| a1.cmp(a2))
+                .map(|pair| match pair {
+                    EitherOrBoth::Both((a, c1), (_, c2)) => (a.clone(), f(c1, c2)),
+                    EitherOrBoth::Left((a, c1)) => (a.clone(), f(c1, &zero)),
+                    EitherOrBoth::Right((a, c2)) => (a.clone(), f(&zero, c2)),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns what this clock has seen that the other has not, and what the other has seen that
+    /// this clock has not.
+    pub fn diff(&self, other: &Self) -> (Self, Self) {
+        (self.subtract(other), other.subtract(self))
+    }
+
+    /// Returns the dots of this clock which are not reflected in a checkpoint, at most one per actor.
+    pub fn changes_since(&self, checkpoint: &Self) -> Vec<Dot<A, C>> {
+        self.subtract(checkpoint).into_iter().collect()
+    }
+
+    /// Returns the meet of two clocks, a clock with the actors present in both clocks and the
+    /// minimum of their counters.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            dots: self
+                .dots
+                .iter()
+                .filter_map(|(a, c)| {
+                    other.dots.get(a).map(|d| {
+                        if c < d {
+                            (a.clone(), *c)
+                        } else {
+                            (a.clone(), *d)
+                        }
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Retains only the actors for which the predicate returns `true`.
+    pub fn retain_actors(&mut self, f: impl Fn(&A) -> bool) {
+        self.dots.retain(|a, _| f(a));
+    }
+
+    /// Removes an actor from the clock, returning its counter if it was present.
+    pub fn remove_actor(&mut self, actor: &A) -> Option<C> {
+        self.dots.remove(actor)
+    }
+
+    /// Removes all the actors whose counter is zero.
+    pub fn prune_zeros(&mut self) {
+        self.dots.retain(|_, c| *c != C::zero());
+    }
+
+    /// Returns a new clock without the actors whose counter is zero.
+    pub fn without_zeros(&self) -> Self {
+        Self {
+            dots: self
+                .dots
+                .iter()
+                .filter(|(_, c)| **c != C::zero())
+                .map(|(a, c)| (a.clone(), *c))
+                .collect(),
+        }
+    }
+
+    /// Merges another clock into this clock and returns a clock with the entries which changed.
+    pub fn merge_returning_delta(&mut self, other: Self) -> Self {
+        let mut delta = Self::default();
+        for (a, c) in other.dots {
+            if c > C::zero() && !self.contains_greater(&a, &c) {
+                self.dots.insert(a.clone(), c);
+                delta.dots.insert(a, c);
+            }
+        }
+        delta
+    }
+
     /// Determines if stored counter for a given actor is greater than the specified counter.
     pub(crate) fn contains_greater(&self, actor: &A, count: &C) -> bool {
-        self.dots.get(actor).map_or(false, |c| c >= count)
+        self.dots.get(actor).is_some_and(|c| c >= count)
     }
 
     /// Determines if stored counter for a given actor is smaller than the specified counter.
+    #[cfg_attr(not(test), allow(dead_code))]
     pub(crate) fn contains_less(&self, actor: &A, count: &C) -> bool {
-        self.dots.get(actor).map_or(false, |c| c <= count)
+        self.dots.get(actor).is_some_and(|c| c <= count)
     }
 
     /// Returns an iterator over the dots in this vclock
     pub fn iter(&self) -> impl Iterator<Item = Dot<A, C>> + '_ {
-        self.dots.iter().map(|(a, c)| Dot::new(*a, *c))
+        self.dots.iter().map(|(a, c)| Dot::new(a.clone(), *c))
+    }
+
+    /// Returns the actor and counter pairs of this vclock, sorted by actor.
+    pub fn to_vec(&self) -> Vec<(A, C)> {
+        self.dots.iter().map(|(a, c)| (a.clone(), *c)).collect()
+    }
+
+    /// Constructs a vclock from actor and counter pairs. When an actor appears several times,
+    /// the last pair wins.
+    pub fn from_vec(pairs: Vec<(A, C)>) -> Self {
+        pairs.into()
+    }
+
+    /// Returns the dots of this vclock, sorted by actor.
+    pub fn to_dot_vec(&self) -> Vec<Dot<A, C>> {
+        self.iter().collect()
+    }
+
+    /// Constructs a vclock from dots. When an actor appears several times, the clock keeps
+    /// the greatest counter.
+    pub fn from_dot_vec(dots: Vec<Dot<A, C>>) -> Self {
+        dots.into_iter().collect()
+    }
+
+    /// Returns an iterator over the actors in this vclock, in ascending order.
+    pub fn actors(&self) -> impl Iterator<Item = &A> + '_ {
+        self.dots.keys()
+    }
+
+    /// Returns an iterator over the pairs whose counter is not zero, in ascending actor order.
+    fn nonzero_dots(&self) -> impl Iterator<Item = (&A, &C)> + '_ {
+        self.dots.iter().filter(|(_, c)| **c != C::zero())
+    }
+
+    /// Returns an iterator over the actors whose counter is greater than zero, in ascending order.
+    pub fn actors_nonzero(&self) -> impl Iterator<Item = &A> + '_ {
+        self.dots
+            .iter()
+            .filter(|(_, c)| **c > C::zero())
+            .map(|(a, _)| a)
+    }
+
+    /// Returns an iterator over the actor and counter pairs in this vclock, sorted by actor.
+    pub fn actor_counters(&self) -> impl Iterator<Item = (&A, &C)> + '_ {
+        self.dots.iter()
+    }
+
+    /// Consumes the vclock and returns an iterator over its actor and counter pairs, sorted by actor.
+    pub fn into_actor_counters(self) -> impl Iterator<Item = (A, C)> {
+        self.dots.into_iter()
     }
 
     fn get_counter_or(&self, default: C, actor: &A) -> C {
@@ -177,91 +657,469 @@ impl<A: Actor, C: Counter> VClock<A, C> {
         self.get_counter_or(C::zero(), actor)
     }
 
+    /// Returns the entry of an actor for in-place manipulation.
+    pub fn entry(&mut self, actor: A) -> VClockEntry<'_, A, C> {
+        VClockEntry {
+            entry: self.dots.entry(actor),
+        }
+    }
+
     fn apply_dot(&mut self, dot: Dot<A, C>) {
-        if self.get_counter_or_zero(&dot.actor) < dot.counter {
-            self.dots.insert(dot.actor, dot.counter);
+        let entry = self.entry(dot.actor).and_modify(|c| {
+            if *c < dot.counter {
+                *c = dot.counter
+            }
+        });
+        if dot.counter > C::zero() {
+            entry.or_insert(dot.counter);
+        }
+    }
+
+    /// Replaces the counter of an actor, zero if the actor is unknown, with the result of a
+    /// function, and returns the new counter.
+    pub fn update_actor(&mut self, actor: A, f: impl FnOnce(C) -> C) -> C {
+        let counter = self.entry(actor).or_zero();
+        *counter = f(*counter);
+        *counter
+    }
+
+    /// Advances the counter of an actor to a given value if it is greater than the current one.
+    /// Returns `true` if the counter changed.
+    pub fn advance_actor(&mut self, actor: A, to: C) -> bool {
+        if to > self.get_counter_or_zero(&actor) {
+            self.dots.insert(actor, to);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Increments the counter of every known actor.
+    pub fn advance_all(&mut self) {
+        self.dots.values_mut().for_each(|c| c.incr_assign());
+    }
+
+    /// Adds a step to the counter of every known actor.
+    pub fn advance_all_by(&mut self, step: C) {
+        self.dots.values_mut().for_each(|c| *c += step);
+    }
+
+    /// Applies a batch of dots in a single pass, keeping the greatest counter for each actor.
+    pub fn apply_all(&mut self, dots: impl IntoIterator<Item = Dot<A, C>>) {
+        let mut maxs = BTreeMap::<A, C>::new();
+        for dot in dots {
+            maxs.entry(dot.actor)
+                .and_modify(|c| {
+                    if *c < dot.counter {
+                        *c = dot.counter
+                    }
+                })
+                .or_insert(dot.counter);
+        }
+
+        for (a, c) in maxs {
+            self.apply_dot(Dot::new(a, c));
         }
     }
 
     /// Increments in-place the `dot` for a specified actor.
     pub(crate) fn incr_assign(&mut self, actor: &A) {
-        *self += Dot::new_one(*actor);
+        self.entry(actor.clone()).or_zero().incr_assign();
     }
 }
 
 //
-// Iterator
+// Merge policies
 //
 
-/// Simple iterator state
-pub struct IntoIter<A: Actor, C: Counter> {
-    /// The iter
-    btree_iter: btree_map::IntoIter<A, C>,
+impl<A: Actor, C: Counter> MergePolicy<VClock<A, C>> for MinMerge {
+    fn resolve(&self, a: &VClock<A, C>, b: &VClock<A, C>) -> VClock<A, C> {
+        a.intersection(b)
+    }
 }
 
-impl<A: Actor, C: Counter> std::iter::Iterator for IntoIter<A, C> {
-    type Item = Dot<A, C>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.btree_iter
-            .next()
-            .map(|(actor, count)| Dot::new(actor, count))
+impl<A: Actor, C: Counter> MergePolicy<VClock<A, C>> for ActorPriorityMerge<A> {
+    fn resolve(&self, a: &VClock<A, C>, b: &VClock<A, C>) -> VClock<A, C> {
+        a.clone() | b.filter_actors(|actor, _| self.is_trusted(actor))
     }
 }
 
-impl<A: Actor, C: Counter> std::iter::IntoIterator for VClock<A, C> {
-    type Item = Dot<A, C>;
-    type IntoIter = IntoIter<A, C>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter {
-            btree_iter: self.dots.into_iter(),
-        }
+impl<A: Actor, C: Counter> VClock<A, C> {
+    /// Merges another clock into this clock, resolving the two clocks with a policy.
+    pub fn merge_with_policy<P: MergePolicy<Self>>(&mut self, other: Self, policy: &P) {
+        *self = policy.resolve(self, &other);
     }
 }
 
 //
-// CvRDT and CmRDT
+// Strict application
 //
 
-impl<A: Actor, C: Counter> CvRDT for VClock<A, C> {
-    type Validation = Infallible;
+/// The error returned by `VClock::strict_apply` when a dot is older than the clock.
+#[derive(Debug, PartialEq)]
+pub struct StaleOpError<A, C> {
+    /// The actor of the stale dot.
+    pub actor: A,
+    /// The counter of the stale dot.
+    pub counter: C,
+    /// The counter stored in the clock for the actor.
+    pub current: C,
+}
 
-    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
-        Ok(())
+impl<A: Display, C: Display> Display for StaleOpError<A, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "stale dot {}:{}, the clock is at {}",
+            self.actor, self.counter, self.current
+        )
     }
+}
 
-    fn merge(&mut self, other: Self) {
-        for dot in other.into_iter() {
-            self.apply_dot(dot);
+impl<A: Debug + Display, C: Debug + Display> core::error::Error for StaleOpError<A, C> {}
+
+impl<A: Actor, C: Counter> VClock<A, C> {
+    /// Applies a dot, or returns an error if its counter is less than the counter stored for
+    /// its actor. Unlike `apply`, which ignores such a dot, this surfaces out of order delivery.
+    pub fn strict_apply(&mut self, dot: Dot<A, C>) -> Result<(), StaleOpError<A, C>> {
+        let current = self.get_counter_or_zero(&dot.actor);
+        if dot.counter < current {
+            return Err(StaleOpError {
+                actor: dot.actor,
+                counter: dot.counter,
+                current,
+            });
         }
+
+        self.apply_dot(dot);
+        Ok(())
     }
 }
 
-impl<A: Actor + Debug + Display, C: Counter + Debug + Display> CmRDT for VClock<A, C> {
-    type Op = Dot<A, C>;
-
-    type Validation = DotRange<A, C>;
+//
+// HashMap conversions
+//
 
-    fn validate_op(&self, dot: &Self::Op) -> Result<(), Self::Validation> {
-        let next_counter = self.get_counter_or_zero(&dot.actor).incr();
-        if dot.counter > next_counter {
-            Err(DotRange {
-                actor: dot.actor.clone(),
-                range: next_counter..dot.counter,
-            })
-        } else {
-            Ok(())
-        }
+#[cfg(feature = "std")]
+impl<A: Actor + Hash, C: Counter> VClock<A, C> {
+    /// Returns the actor and counter pairs of the clock as a `HashMap`.
+    pub fn to_hashmap(&self) -> HashMap<A, C> {
+        self.dots.iter().map(|(a, c)| (a.clone(), *c)).collect()
     }
 
-    fn apply(&mut self, dot: Self::Op) {
-        if self.get_counter_or_zero(&dot.actor) < dot.counter {
-            self.dots.insert(dot.actor, dot.counter);
+    /// Constructs a clock from a `HashMap` of actor and counter pairs.
+    pub fn from_hashmap(map: HashMap<A, C>) -> Self {
+        Self {
+            dots: map.into_iter().collect(),
         }
     }
 }
 
+//
+// Compact encoding
+//
+
+impl<A: Actor + VarInt, C: Counter + VarInt> VClock<A, C> {
+    /// Returns the compact binary encoding of the clock.
+    pub fn encode_compact(&self) -> Vec<u8> {
+        let mut out = vec![COMPACT_VERSION];
+        self.dots.len().encode_varint(&mut out);
+        for (a, c) in self.dots.iter() {
+            a.encode_varint(&mut out);
+            c.encode_varint(&mut out);
+        }
+        out
+    }
+
+    /// Decodes a clock from its compact binary encoding.
+    pub fn decode_compact(mut bytes: &[u8]) -> Result<Self, DecodeError> {
+        decode_version(&mut bytes)?;
+
+        let mut clock = Self::default();
+        for _ in 0..usize::decode_varint(&mut bytes)? {
+            let actor = A::decode_varint(&mut bytes)?;
+            let counter = C::decode_varint(&mut bytes)?;
+            if clock.dots.insert(actor, counter).is_some() {
+                return Err(DecodeError::DuplicateActor);
+            }
+        }
+
+        decode_end(bytes)?;
+        Ok(clock)
+    }
+}
+
+#[cfg(feature = "base64")]
+impl<A: Actor + VarInt, C: Counter + VarInt> VClock<A, C> {
+    /// Returns the compact binary encoding of the clock as URL-safe base64 text.
+    pub fn to_base64(&self) -> alloc::string::String {
+        crate::compact::to_base64(&self.encode_compact())
+    }
+
+    /// Decodes a clock from the URL-safe base64 text of its compact binary encoding.
+    pub fn from_base64(s: &str) -> Result<Self, DecodeError> {
+        Self::decode_compact(&crate::compact::from_base64(s)?)
+    }
+}
+
+//
+// Integrity
+//
+
+/// The inconsistencies reported by `VClock::validate_integrity`.
+#[derive(Debug, PartialEq)]
+pub enum VClockIntegrityError<A> {
+    /// The counter of the actor is less than zero.
+    NegativeCounter(A),
+    /// The actor is stored more than once.
+    DuplicateActor(A),
+    /// The actor and the one stored after it are not ordered consistently by `Ord`.
+    UnorderedActors(A),
+}
+
+impl<A: Display> Display for VClockIntegrityError<A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VClockIntegrityError::NegativeCounter(a) => write!(f, "negative counter for {}", a),
+            VClockIntegrityError::DuplicateActor(a) => write!(f, "duplicate actor {}", a),
+            VClockIntegrityError::UnorderedActors(a) => write!(f, "unordered actor {}", a),
+        }
+    }
+}
+
+impl<A: Debug + Display> core::error::Error for VClockIntegrityError<A> {}
+
+impl<A: Actor, C: Counter> VClock<A, C> {
+    /// Checks the invariants of the clock, which is useful after loading it from storage:
+    /// every counter is at least zero and the actors are unique and strictly ascending.
+    pub fn validate_integrity(&self) -> Result<(), VClockIntegrityError<A>> {
+        if let Some((a, _)) = self.dots.iter().find(|(_, c)| **c < C::zero()) {
+            return Err(VClockIntegrityError::NegativeCounter(a.clone()));
+        }
+
+        for (a, b) in self.dots.keys().tuple_windows() {
+            match (a.cmp(b), b.cmp(a)) {
+                (core::cmp::Ordering::Less, core::cmp::Ordering::Greater) => {}
+                (core::cmp::Ordering::Equal, _) => {
+                    return Err(VClockIntegrityError::DuplicateActor(a.clone()))
+                }
+                _ => return Err(VClockIntegrityError::UnorderedActors(a.clone())),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//
+// Parallel merge
+//
+
+#[cfg(feature = "rayon")]
+impl<A: Actor + Send + Sync, C: Counter + Send + Sync> VClock<A, C> {
+    /// Merges several clocks into this clock. The actors are split into ranges, taken from the
+    /// largest clock, and each range is merged on its own rayon task.
+    pub fn parallel_merge(&mut self, others: Vec<Self>) {
+        use core::ops::Bound;
+        use rayon::prelude::*;
+
+        let clocks: Vec<&BTreeMap<A, C>> = core::iter::once(&self.dots)
+            .chain(others.iter().map(|other| &other.dots))
+            .collect();
+
+        let largest = clocks.iter().map(|dots| dots.len()).max().unwrap_or(0);
+        let step = (largest / rayon::current_num_threads()).max(1);
+        let splits: Vec<A> = clocks
+            .iter()
+            .find(|dots| dots.len() == largest)
+            .map(|dots| dots.keys().step_by(step).skip(1).cloned().collect())
+            .unwrap_or_default();
+
+        let mut lower = Bound::Unbounded;
+        let mut ranges = Vec::with_capacity(splits.len() + 1);
+        for split in splits {
+            ranges.push((lower, Bound::Excluded(split.clone())));
+            lower = Bound::Included(split);
+        }
+        ranges.push((lower, Bound::Unbounded));
+
+        let parts: Vec<BTreeMap<A, C>> = ranges
+            .into_par_iter()
+            .map(|range| {
+                let mut part = BTreeMap::new();
+                for dots in clocks.iter() {
+                    for (a, c) in dots.range(range.clone()) {
+                        part.entry(a.clone())
+                            .and_modify(|p: &mut C| {
+                                if *p < *c {
+                                    *p = *c
+                                }
+                            })
+                            .or_insert(*c);
+                    }
+                }
+                part
+            })
+            .collect();
+
+        self.dots = parts.into_iter().flatten().collect();
+    }
+}
+
+//
+// Entry
+//
+
+/// A view into the counter of a single actor of a `VClock`.
+pub struct VClockEntry<'a, A: Actor, C: Counter> {
+    entry: btree_map::Entry<'a, A, C>,
+}
+
+impl<'a, A: Actor, C: Counter> VClockEntry<'a, A, C> {
+    /// Returns the actor of the entry.
+    pub fn actor(&self) -> &A {
+        self.entry.key()
+    }
+
+    /// Inserts a zero counter if the actor is missing and returns a reference to its counter.
+    pub fn or_zero(self) -> &'a mut C {
+        self.entry.or_insert_with(C::zero)
+    }
+
+    /// Inserts the given counter if the actor is missing and returns a reference to its counter.
+    pub fn or_insert(self, counter: C) -> &'a mut C {
+        self.entry.or_insert(counter)
+    }
+
+    /// Modifies in-place the counter of the actor, if the actor is present.
+    pub fn and_modify(self, f: impl FnOnce(&mut C)) -> Self {
+        Self {
+            entry: self.entry.and_modify(f),
+        }
+    }
+}
+
+//
+// Iterator
+//
+
+/// Simple iterator state
+pub struct IntoIter<A: Actor, C: Counter> {
+    /// The iter
+    btree_iter: btree_map::IntoIter<A, C>,
+}
+
+impl<A: Actor, C: Counter> core::iter::Iterator for IntoIter<A, C> {
+    type Item = Dot<A, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.btree_iter
+            .next()
+            .map(|(actor, count)| Dot::new(actor, count))
+    }
+}
+
+impl<A: Actor, C: Counter> core::iter::IntoIterator for VClock<A, C> {
+    type Item = Dot<A, C>;
+    type IntoIter = IntoIter<A, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            btree_iter: self.dots.into_iter(),
+        }
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor, C: Counter> CvRDT for VClock<A, C> {
+    type Validation = Infallible;
+
+    fn validate_merge(&self, _: &Self) -> Result<(), Self::Validation> {
+        Ok(())
+    }
+
+    fn merge(&mut self, other: Self) {
+        for dot in other.into_iter() {
+            self.apply_dot(dot);
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> DeltaCRDT for VClock<A, C> {
+    fn delta_since(&self, checkpoint: &Self) -> Self {
+        self.subtract(checkpoint)
+    }
+}
+
+/// The versioned state of a `VClock`, which can be persisted and restored later.
+///
+/// With the `serde` feature the snapshot is serializable. Unknown fields are ignored when
+/// deserializing, so newer fields can be added without breaking older readers.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct VClockSnapshot<A, C> {
+    /// The schema version of the snapshot.
+    pub version: u8,
+    /// The actor and counter pairs, in ascending actor order.
+    pub dots: Vec<(A, C)>,
+}
+
+impl<A, C> From<Vec<(A, C)>> for VClockSnapshot<A, C> {
+    fn from(dots: Vec<(A, C)>) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            dots,
+        }
+    }
+}
+
+impl<A: Actor, C: Counter> Snapshot for VClock<A, C> {
+    type SnapshotData = VClockSnapshot<A, C>;
+
+    fn snapshot(&self) -> Self::SnapshotData {
+        self.to_vec().into()
+    }
+
+    fn restore(data: Self::SnapshotData) -> Result<Self, SnapshotError> {
+        if data.version > SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(data.version));
+        }
+
+        let mut clock = Self::default();
+        for (a, c) in data.dots {
+            if clock.dots.insert(a, c).is_some() {
+                return Err(SnapshotError::DuplicateActor);
+            }
+        }
+        Ok(clock)
+    }
+}
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> CmRDT for VClock<A, C> {
+    type Op = Dot<A, C>;
+
+    type Validation = DotRange<A, C>;
+
+    fn validate_op(&self, dot: &Self::Op) -> Result<(), Self::Validation> {
+        let next_counter = self.get_counter_or_zero(&dot.actor).incr();
+        if dot.counter > next_counter {
+            Err(DotRange {
+                actor: dot.actor.clone(),
+                range: next_counter..dot.counter,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn apply(&mut self, dot: Self::Op) {
+        self.apply_dot(dot);
+    }
+}
+
 //
 // Tests
 //
@@ -305,6 +1163,75 @@ mod tests {
         !clock.is_empty() && clock.len() == 1
     }
 
+    #[quickcheck]
+    fn test_from_iter(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.iter().map(|p| Dot::from(*p)).collect();
+        pairs.iter().all(|(a, c)| clock.contains_dot(&Dot::new(*a, *c)))
+            && clock.actors().all(|a| pairs.iter().any(|(b, _)| a == b))
+    }
+
+    #[test]
+    fn test_string_actors() {
+        let mut clock1: VClock<String, usize> = VClock::default();
+        clock1.apply(clock1.dot_incremented(&"alice".to_string()));
+        let mut clock2 = clock1.clone();
+        clock2.apply(clock2.dot_incremented(&"bob".to_string()));
+
+        assert!(clock1 < clock2);
+        clock1.merge(clock2);
+        assert_eq!(1, clock1.dot_or_zero(&"bob".to_string()).counter);
+    }
+
+    #[test]
+    fn test_from_iter_max() {
+        let dots = vec![Dot::new(1, 10), Dot::new(2, 20), Dot::new(1, 5)];
+        let clock: VClock<i8, usize> = dots.into_iter().collect();
+        let expected: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        assert!(clock == expected);
+    }
+
+    #[quickcheck]
+    fn test_parse_roundtrip(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        clock == clock.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let clock: VClock<i8, usize> = "<1:10,-2:20>".parse().unwrap();
+        let expected: VClock<i8, usize> = vec![(1, 10), (-2, 20)].into();
+        assert!(clock == expected);
+
+        let clock: VClock<i8, usize> = "<>".parse().unwrap();
+        assert!(clock.is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |s: &str| s.parse::<VClock<i8, usize>>().err().unwrap();
+
+        let err = parse("1:10>");
+        assert_eq!(ParseVClockErrorKind::MissingOpeningBracket, err.kind);
+        assert_eq!(0, err.position);
+
+        let err = parse("<1:10");
+        assert_eq!(ParseVClockErrorKind::MissingClosingBracket, err.kind);
+        assert_eq!(5, err.position);
+
+        let err = parse("<1:10,2>");
+        assert_eq!(ParseVClockErrorKind::MissingColon, err.kind);
+        assert_eq!(6, err.position);
+
+        let err = parse("<1:10,x:20>");
+        assert_eq!(ParseVClockErrorKind::InvalidActor, err.kind);
+        assert_eq!(6, err.position);
+
+        let err = parse("<1:10,2:-20>");
+        assert_eq!(ParseVClockErrorKind::InvalidCounter, err.kind);
+        assert_eq!(8, err.position);
+        assert_eq!("invalid counter at position 8", err.to_string());
+    }
+
     #[test]
     fn test_dot_or_zero() {
         let dots: Vec<(i8, usize)> = vec![(1, 10), (2, 20), (3, 30)];
@@ -329,6 +1256,25 @@ mod tests {
         assert!(dot.actor == 4 && dot.counter == 1);
     }
 
+    #[quickcheck]
+    fn test_contains_dot(pairs: Vec<(i8, u16)>) -> bool {
+        let pairs: Vec<(i8, u32)> = pairs.into_iter().map(|(a, c)| (a, c as u32)).collect();
+        let clock: VClock<i8, u32> = pairs.into();
+        let dots: Vec<Dot<i8, u32>> = clock.iter().collect();
+        dots.into_iter().all(|dot| {
+            clock.contains_dot(&dot)
+                && clock.contains_dot(&dot.upd(dot.counter / 2))
+                && !clock.contains_dot(&dot.incr())
+        })
+    }
+
+    #[quickcheck]
+    fn test_contains_dot_unknown_actor(pairs: Vec<(i8, u32)>, actor: i8) -> bool {
+        let pairs: Vec<(i8, u32)> = pairs.into_iter().filter(|(a, _)| *a != actor).collect();
+        let clock: VClock<i8, u32> = pairs.into();
+        clock.contains_dot(&Dot::new_zero(actor)) && !clock.contains_dot(&Dot::new_one(actor))
+    }
+
     #[test]
     fn test_contains_greater() {
         let clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
@@ -386,91 +1332,462 @@ mod tests {
     }
 
     #[test]
-    fn test_causality_concurrent_diff_len() {
+    fn test_causality_succeed_diff_len() {
         let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
         let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30), (4, 40)].into();
 
-        assert_eq!(clock2.causality_cmp(&clock1), Causality::Concurrent);
+        assert_eq!(clock2.causality_cmp(&clock1), Causality::Succeed);
     }
 
     #[test]
-    fn test_eq_diff_len() {
-        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
-        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30), (4, 40)].into();
+    fn test_causality_missing_actor_is_zero() {
+        let mut clock1: VClock<i8, usize> = vec![(1, 10)].into();
+        clock1.entry(2).or_zero();
+        let clock2: VClock<i8, usize> = vec![(1, 10)].into();
 
-        assert!(clock1 != clock2);
-        assert!(clock2 != clock1);
+        assert_eq!(clock1.causality_cmp(&clock2), Causality::Equal);
+        assert!(clock1 == clock2);
+        assert_eq!(clock1.total_cmp(&clock2), core::cmp::Ordering::Equal);
     }
 
-    #[test]
-    fn test_eq_diff_item() {
-        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
-        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (4, 40)].into();
-
-        assert!(clock1 != clock2);
-        assert!(clock2 != clock1);
+    #[quickcheck]
+    fn test_causality_exclusive(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let a: VClock<i8, usize> = pairs1.into();
+        let b: VClock<i8, usize> = pairs2.into();
+        a.dominates(&b) ^ a.is_concurrent_with(&b) ^ (a == b) ^ b.dominates(&a)
     }
 
     #[test]
-    fn test_iter() {
-        let clock: VClock<i8, i32> = vec![(1, 10), (2, 20), (3, 30)].into();
-        let ttl = clock.iter().map(|dot| dot.counter).sum();
-        assert_eq!(60, ttl);
+    fn test_is_concurrent_with() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 10), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 20)].into();
+
+        assert!(clock1.is_concurrent_with(&clock2));
+        assert!(clock2.is_concurrent_with(&clock1));
+        assert!(!clock1.is_concurrent_with(&clock1));
     }
 
     #[test]
-    fn test_intoiter() {
-        let clock: VClock<i8, i32> = vec![(1, 10), (2, 20), (3, 30)].into();
-        let mut iter = clock.into_iter();
-        assert_eq!(iter.next().map(|d| d.counter).unwrap(), 10);
-        assert_eq!(iter.next().map(|d| d.counter).unwrap(), 20);
-        assert_eq!(iter.next().map(|d| d.counter).unwrap(), 30);
+    fn test_dominates() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 30), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+
+        assert!(clock1.dominates(&clock2));
+        assert!(!clock2.dominates(&clock1));
+        assert!(!clock1.dominates(&clock1));
     }
 
     #[test]
-    fn merge() {
-        let mut clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
-        let clock2: VClock<i8, usize> = vec![(1, 100), (2, 10), (4, 40)].into();
+    fn test_is_dominated_by() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 10), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30), (4, 40)].into();
 
-        clock1.merge(clock2);
-        assert_eq!(100, clock1.get_counter_or_zero(&1));
-        assert_eq!(20, clock1.get_counter_or_zero(&2));
-        assert_eq!(30, clock1.get_counter_or_zero(&3));
-        assert_eq!(40, clock1.get_counter_or_zero(&4));
+        assert!(clock1.is_dominated_by(&clock2));
+        assert!(!clock2.is_dominated_by(&clock1));
+        assert!(!clock1.is_dominated_by(&clock1));
     }
 
     #[test]
-    fn apply_dot() {
-        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+    fn test_subtract() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 10), (4, 40)].into();
 
-        let dot1: Dot<i8, usize> = Dot::new(1, 100);
-        clock.apply(dot1);
-        assert_eq!(clock.get_counter_or_zero(&1), 100);
+        let expected: VClock<i8, usize> = vec![(2, 20), (3, 30)].into();
+        assert!(clock1.subtract(&clock2) == expected);
+    }
 
-        let dot2: Dot<i8, usize> = Dot::new(2, 10);
-        clock.apply(dot2);
-        assert_eq!(clock.get_counter_or_zero(&2), 20);
+    #[quickcheck]
+    fn test_subtract_self(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        clock.subtract(&clock).is_empty()
+    }
 
-        let dot3: Dot<i8, usize> = Dot::new(4, 40);
-        clock.apply(dot3);
-        assert_eq!(clock.get_counter_or_zero(&4), 40);
+    #[quickcheck]
+    fn test_subtract_empty(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        clock.subtract(&VClock::default()) == clock
     }
 
     #[test]
-    fn incr_assign_existing() {
-        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
-        clock.incr_assign(&1);
-        assert_eq!(11, clock.get_counter_or_zero(&1));
+    fn test_intersection() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 20), (2, 10), (4, 40)].into();
+
+        let expected: VClock<i8, usize> = vec![(1, 10), (2, 10)].into();
+        assert!(clock1.intersection(&clock2) == expected);
     }
 
-    #[test]
-    fn incr_assign_notexisting() {
-        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
-        clock.incr_assign(&4);
-        assert_eq!(1, clock.get_counter_or_zero(&4));
+    #[quickcheck]
+    fn test_intersection_self(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        clock.intersection(&clock) == clock
     }
 
-    #[test]
+    #[quickcheck]
+    fn test_intersection_empty(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        clock.intersection(&VClock::default()).is_empty()
+    }
+
+    #[quickcheck]
+    fn test_intersection_lower_bound(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs1.into();
+        let clock2: VClock<i8, usize> = pairs2.into();
+
+        let meet = clock1.intersection(&clock2);
+        meet <= clock1 && meet <= clock2
+    }
+
+    #[test]
+    fn test_retain_actors() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        clock.retain_actors(|a| *a != 2);
+
+        let expected: VClock<i8, usize> = vec![(1, 10), (3, 30)].into();
+        assert!(clock == expected);
+    }
+
+    #[test]
+    fn test_remove_actor() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        assert_eq!(Some(20), clock.remove_actor(&2));
+        assert_eq!(None, clock.remove_actor(&2));
+        assert_eq!(2, clock.len());
+    }
+
+    #[quickcheck]
+    fn test_remove_actor_merge(pairs: Vec<(i8, usize)>, actor: i8) -> bool {
+        // merging never introduces actors with a zero counter
+        let pairs: Vec<(i8, usize)> = pairs.into_iter().filter(|(_, c)| *c > 0).collect();
+        let full: VClock<i8, usize> = pairs.clone().into();
+        let mut pruned: VClock<i8, usize> = pairs.clone().into();
+        pruned.remove_actor(&actor);
+
+        pruned.merge(full);
+        pruned == pairs.into()
+    }
+
+    #[quickcheck]
+    fn test_retain_actors_merge(pairs: Vec<(i8, usize)>) -> bool {
+        // merging never introduces actors with a zero counter
+        let pairs: Vec<(i8, usize)> = pairs.into_iter().filter(|(_, c)| *c > 0).collect();
+        let full: VClock<i8, usize> = pairs.clone().into();
+        let mut pruned: VClock<i8, usize> = pairs.clone().into();
+        pruned.retain_actors(|a| a % 2 == 0);
+
+        pruned.merge(full);
+        pruned == pairs.into()
+    }
+
+    #[test]
+    fn test_hash_set() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(VClock::<i8, usize>::from(vec![(1, 10), (2, 20)]));
+        set.insert(VClock::<i8, usize>::from(vec![(2, 20), (1, 10)]));
+        set.insert(VClock::<i8, usize>::from(vec![(1, 10)]));
+
+        assert_eq!(2, set.len());
+        assert!(set.contains(&vec![(1, 10)].into()));
+    }
+
+    #[test]
+    fn test_total_cmp() {
+        let a: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let b: VClock<i8, usize> = vec![(1, 20), (2, 10)].into();
+        let c: VClock<i8, usize> = vec![(3, 1)].into();
+
+        assert_eq!(None, a.partial_cmp(&b));
+        assert_eq!(core::cmp::Ordering::Less, a.total_cmp(&b));
+        assert_eq!(core::cmp::Ordering::Greater, a.total_cmp(&c));
+        assert_eq!(core::cmp::Ordering::Equal, a.total_cmp(&a.clone()));
+    }
+
+    #[quickcheck]
+    fn test_total_cmp_antisymmetric(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let a: VClock<i8, usize> = pairs1.into();
+        let b: VClock<i8, usize> = pairs2.into();
+        a.total_cmp(&b) == b.total_cmp(&a).reverse()
+            && (a.total_cmp(&b) == core::cmp::Ordering::Equal) == (a == b)
+    }
+
+    #[test]
+    fn test_total_ord_btree_set() {
+        let set: alloc::collections::BTreeSet<TotalOrdVClock<i8, usize>> = [
+            vec![(1, 20), (2, 10)],
+            vec![(1, 10), (2, 20)],
+            vec![(2, 20), (1, 10)],
+            vec![(3, 1)],
+        ]
+        .into_iter()
+        .map(|pairs| VClock::from(pairs).into())
+        .collect();
+
+        assert_eq!(3, set.len());
+        let clocks: Vec<_> = set.into_iter().map(|c| c.0).collect();
+        assert!(clocks[0] == vec![(3, 1)].into());
+        assert!(clocks[1] == vec![(1, 10), (2, 20)].into());
+        assert!(clocks[2] == vec![(1, 20), (2, 10)].into());
+    }
+
+    #[quickcheck]
+    fn test_total_ord_sort(pairs: Vec<Vec<(i8, usize)>>) -> bool {
+        let mut clocks: Vec<TotalOrdVClock<i8, usize>> =
+            pairs.into_iter().map(|p| VClock::from(p).into()).collect();
+        clocks.sort();
+        clocks.windows(2).all(|w| w[0].0.total_cmp(&w[1].0).is_le())
+    }
+
+    #[quickcheck]
+    fn test_is_dominated_by_dominates(pairs1: Vec<(i8, u8)>, pairs2: Vec<(i8, u8)>) -> bool {
+        let a: VClock<i8, u8> = pairs1.into();
+        let b: VClock<i8, u8> = pairs2.into();
+        a.is_dominated_by(&b) == b.dominates(&a)
+            && a.is_dominated_by(&b) == (a.causality_cmp(&b) == Causality::Precede)
+    }
+
+    #[test]
+    fn test_dominates_superset() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+
+        assert!(clock2.dominates(&clock1));
+        assert!(!clock1.dominates(&clock2));
+        assert!(!clock1.is_dominated_by(&vec![(1, 20), (2, 10)].into()));
+    }
+
+    #[test]
+    fn test_is_zero() {
+        let mut clock = VClock::<i8, usize>::default();
+        assert!(clock.is_zero());
+
+        clock.entry(1).or_zero();
+        assert!(!clock.is_empty());
+        assert!(clock.is_zero());
+
+        clock.incr_assign(&1);
+        assert!(!clock.is_zero());
+    }
+
+    #[quickcheck]
+    fn test_len_nonzero(pairs: Vec<(i8, u8)>) -> bool {
+        let mut clock: VClock<i8, u8> = pairs.into();
+        let len_nonzero = clock.len_nonzero();
+        let fits = len_nonzero <= clock.len();
+
+        clock.prune_zeros();
+        fits && clock.len() == len_nonzero
+    }
+
+    #[test]
+    fn test_actors_nonzero() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (3, 30)].into();
+        clock.entry(2).or_zero();
+
+        assert_eq!(3, clock.len());
+        assert_eq!(2, clock.len_nonzero());
+        assert_eq!(vec![&1, &3], clock.actors_nonzero().collect::<Vec<_>>());
+    }
+
+    #[quickcheck]
+    fn test_is_zero_prune_zeros(pairs: Vec<(i8, u8)>) -> bool {
+        let mut clock: VClock<i8, u8> = pairs.into();
+        let is_zero = clock.is_zero();
+
+        clock.prune_zeros();
+        is_zero == clock.is_empty()
+    }
+
+    #[quickcheck]
+    fn test_prune_zeros(pairs: Vec<(i8, u8)>) -> bool {
+        let mut clock: VClock<i8, u8> = pairs.into();
+        let len = clock.iter().filter(|d| d.counter != 0).count();
+
+        clock.prune_zeros();
+        clock.iter().all(|d| d.counter != 0) && clock.len() == len
+    }
+
+    #[quickcheck]
+    fn test_without_zeros(pairs: Vec<(i8, u8)>) -> bool {
+        let clock: VClock<i8, u8> = pairs.into();
+        let mut pruned: VClock<i8, u8> = clock.iter().collect();
+        pruned.prune_zeros();
+
+        clock.without_zeros() == pruned
+    }
+
+    #[test]
+    fn test_eq_diff_len() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30), (4, 40)].into();
+
+        assert!(clock1 != clock2);
+        assert!(clock2 != clock1);
+    }
+
+    #[test]
+    fn test_eq_diff_item() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 10), (2, 20), (4, 40)].into();
+
+        assert!(clock1 != clock2);
+        assert!(clock2 != clock1);
+    }
+
+    #[test]
+    fn test_iter() {
+        let clock: VClock<i8, i32> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let ttl: i32 = clock.iter().map(|dot| dot.counter).sum();
+        assert_eq!(60, ttl);
+    }
+
+    #[test]
+    fn test_actors() {
+        let clock: VClock<i8, i32> = vec![(3, 30), (1, 10), (2, 20)].into();
+        assert_eq!(vec![&1, &2, &3], clock.actors().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_actor_counters() {
+        let clock: VClock<i8, i32> = vec![(3, 30), (1, 10), (2, 20)].into();
+        assert_eq!(
+            vec![(&1, &10), (&2, &20), (&3, &30)],
+            clock.actor_counters().collect::<Vec<_>>()
+        );
+    }
+
+    #[quickcheck]
+    fn test_into_actor_counters(pairs: Vec<(i8, i32)>) -> bool {
+        let clock: VClock<i8, i32> = pairs.into();
+        let expected: Vec<(i8, i32)> = clock.iter().map(|d| (d.actor, d.counter)).collect();
+        clock.into_actor_counters().eq(expected)
+    }
+
+    #[test]
+    fn test_intoiter() {
+        let clock: VClock<i8, i32> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let mut iter = clock.into_iter();
+        assert_eq!(iter.next().map(|d| d.counter).unwrap(), 10);
+        assert_eq!(iter.next().map(|d| d.counter).unwrap(), 20);
+        assert_eq!(iter.next().map(|d| d.counter).unwrap(), 30);
+    }
+
+    #[test]
+    fn merge() {
+        let mut clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 100), (2, 10), (4, 40)].into();
+
+        clock1.merge(clock2);
+        assert_eq!(100, clock1.get_counter_or_zero(&1));
+        assert_eq!(20, clock1.get_counter_or_zero(&2));
+        assert_eq!(30, clock1.get_counter_or_zero(&3));
+        assert_eq!(40, clock1.get_counter_or_zero(&4));
+    }
+
+    #[test]
+    fn merge_returning_delta() {
+        let mut clock1: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 100), (2, 10), (4, 40)].into();
+
+        let delta = clock1.merge_returning_delta(clock2);
+        let expected: VClock<i8, usize> = vec![(1, 100), (4, 40)].into();
+        assert!(delta == expected);
+
+        let merged: VClock<i8, usize> = vec![(1, 100), (2, 20), (3, 30), (4, 40)].into();
+        assert!(clock1 == merged);
+    }
+
+    #[test]
+    fn merge_returning_delta_zero() {
+        let mut clock: VClock<i8, usize> = vec![(2, 20)].into();
+        let delta = clock.merge_returning_delta(vec![(1, 0)].into());
+        assert!(delta.is_empty());
+        assert_eq!(1, clock.len());
+    }
+
+    #[quickcheck]
+    fn merge_returning_delta_subtract(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let old: VClock<i8, usize> = pairs1.clone().into();
+        let other: VClock<i8, usize> = pairs2.clone().into();
+        let expected = other.subtract(&old);
+
+        let mut clock: VClock<i8, usize> = pairs1.into();
+        let delta = clock.merge_returning_delta(pairs2.into());
+
+        let mut merged = old;
+        merged.merge(other);
+        delta == expected && clock == merged
+    }
+
+    #[quickcheck]
+    fn merge_returning_delta_self(pairs: Vec<(i8, usize)>) -> bool {
+        let mut clock: VClock<i8, usize> = pairs.clone().into();
+        clock.merge_returning_delta(pairs.into()).is_empty()
+    }
+
+    #[test]
+    fn apply_dot() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+
+        let dot1: Dot<i8, usize> = Dot::new(1, 100);
+        clock.apply(dot1);
+        assert_eq!(clock.get_counter_or_zero(&1), 100);
+
+        let dot2: Dot<i8, usize> = Dot::new(2, 10);
+        clock.apply(dot2);
+        assert_eq!(clock.get_counter_or_zero(&2), 20);
+
+        let dot3: Dot<i8, usize> = Dot::new(4, 40);
+        clock.apply(dot3);
+        assert_eq!(clock.get_counter_or_zero(&4), 40);
+    }
+
+    #[test]
+    fn incr_assign_existing() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        clock.incr_assign(&1);
+        assert_eq!(11, clock.get_counter_or_zero(&1));
+    }
+
+    #[test]
+    fn incr_assign_notexisting() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        clock.incr_assign(&4);
+        assert_eq!(1, clock.get_counter_or_zero(&4));
+    }
+
+    #[test]
+    fn entry_or_zero() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10)].into();
+        assert_eq!(10, *clock.entry(1).or_zero());
+        assert_eq!(0, *clock.entry(2).or_zero());
+        assert_eq!(2, clock.len());
+    }
+
+    #[test]
+    fn apply_zero_dot() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10)].into();
+        clock.merge(vec![(2, 0)].into());
+        clock.apply(Dot::new(3, 0));
+        assert_eq!(1, clock.len());
+    }
+
+    #[test]
+    fn entry_or_insert() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10)].into();
+        assert_eq!(10, *clock.entry(1).or_insert(5));
+        assert_eq!(5, *clock.entry(2).or_insert(5));
+    }
+
+    #[test]
+    fn entry_and_modify() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10)].into();
+        clock.entry(1).and_modify(|c| *c *= 2).or_zero();
+        clock.entry(2).and_modify(|c| *c *= 2).or_insert(7);
+        assert_eq!(20, clock.get_counter_or_zero(&1));
+        assert_eq!(7, clock.get_counter_or_zero(&2));
+    }
+
+    #[test]
     fn add_assign_existing() {
         let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
         clock += Dot::new_one(1);
@@ -497,4 +1814,588 @@ mod tests {
         clock.upd_assign(Dot::new_one(4));
         assert_eq!(1, clock.get_counter_or_zero(&4));
     }
+
+    #[test]
+    fn apply_all() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (3, 30)].into();
+        clock.apply_all(vec![
+            Dot::new(1, 5),
+            Dot::new(2, 20),
+            Dot::new(1, 15),
+            Dot::new(2, 12),
+        ]);
+        let expected: VClock<i8, usize> = vec![(1, 15), (2, 20), (3, 30)].into();
+        assert!(clock == expected);
+    }
+
+    #[quickcheck]
+    fn test_apply_all(pairs: Vec<(i8, usize)>, dots: Vec<(i8, usize)>) -> bool {
+        let mut batched: VClock<i8, usize> = pairs.into();
+        let mut single = batched.clone();
+        let dots: Vec<Dot<i8, usize>> = dots.into_iter().map(|(a, c)| Dot::new(a, c)).collect();
+
+        for dot in dots.iter() {
+            single.apply(*dot);
+        }
+        batched.apply_all(dots);
+
+        batched == single
+    }
+
+    #[quickcheck]
+    fn test_delta_since(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs1.into();
+        let checkpoint: VClock<i8, usize> = pairs2.into();
+
+        let mut merged = checkpoint.clone();
+        merged.merge(clock.clone());
+
+        let mut updated = checkpoint.clone();
+        updated.apply_delta(clock.delta_since(&checkpoint));
+
+        updated == merged
+    }
+
+    #[quickcheck]
+    fn test_snapshot_roundtrip(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        let restored = VClock::restore(clock.snapshot()).unwrap();
+        restored == clock
+    }
+
+    #[test]
+    fn restore_duplicate_actor() {
+        let restored = VClock::<i8, usize>::restore(vec![(1, 10), (2, 20), (1, 5)].into());
+        assert!(matches!(restored, Err(SnapshotError::DuplicateActor)));
+    }
+
+    #[test]
+    fn restore_unsupported_version() {
+        let snapshot = VClockSnapshot {
+            version: SNAPSHOT_VERSION + 1,
+            dots: vec![(1i8, 10usize)],
+        };
+        assert!(matches!(
+            VClock::restore(snapshot),
+            Err(SnapshotError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_serde() {
+        let clock: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let json = serde_json::to_string(&clock.snapshot()).unwrap();
+        assert_eq!(r#"{"version":1,"dots":[[1,10],[2,20]]}"#, json);
+
+        let snapshot: VClockSnapshot<i8, usize> =
+            serde_json::from_str(r#"{"version":1,"dots":[[1,10],[2,20]],"extra":true}"#).unwrap();
+        assert!(VClock::restore(snapshot).unwrap() == clock);
+    }
+
+    #[cfg(feature = "testing")]
+    mod law_tests {
+        use super::*;
+        use crate::testing::*;
+
+        type Pairs = Vec<(i8, usize)>;
+
+        fn make(pairs: &Pairs) -> VClock<i8, usize> {
+            pairs.clone().into()
+        }
+
+        fn make_op(pair: &(i8, usize)) -> Dot<i8, usize> {
+            Dot::new(pair.0, pair.1)
+        }
+
+        #[test]
+        fn cvrdt_laws() {
+            assert_cvrdt_commutativity(make);
+            assert_cvrdt_associativity(make);
+            assert_cvrdt_idempotence(make);
+        }
+
+        #[test]
+        fn cmrdt_laws() {
+            assert_cmrdt_idempotence(make, make_op);
+        }
+    }
+
+    #[quickcheck]
+    fn test_clone(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        let len = clock.len();
+        let mut cloned = clock.clone();
+        let equal = cloned == clock;
+
+        cloned.retain_actors(|_| false);
+        equal && cloned.is_empty() && clock.len() == len
+    }
+
+    #[quickcheck]
+    fn test_bitor(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs1.into();
+        let clock2: VClock<i8, usize> = pairs2.into();
+
+        let mut merged = clock1.clone();
+        merged.merge(clock2.clone());
+
+        let joined = clock1.clone() | clock2.clone();
+        joined == merged && clock1 <= joined && clock2 <= joined
+    }
+
+    #[quickcheck]
+    fn test_add(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs1.into();
+        let clock2: VClock<i8, usize> = pairs2.into();
+
+        let mut merged = clock1.clone();
+        merged.merge(clock2.clone());
+
+        let mut accumulated = clock1.clone();
+        accumulated += clock2.clone();
+
+        accumulated == merged && clock1 + clock2 == merged
+    }
+
+    #[quickcheck]
+    fn test_bitand(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs1.into();
+        let clock2: VClock<i8, usize> = pairs2.into();
+
+        let common = clock1.clone() & clock2.clone();
+        common == clock1.intersection(&clock2) && common == clock2.clone() & clock1.clone()
+    }
+
+    #[quickcheck]
+    fn test_sub(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs1.into();
+        let clock2: VClock<i8, usize> = pairs2.into();
+
+        let delta = clock1.clone() - clock2.clone();
+        delta == clock1.subtract(&clock2) && (clock1.clone() - clock1).is_empty()
+    }
+
+    #[quickcheck]
+    fn test_sub_assign(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs1.into();
+        let clock2: VClock<i8, usize> = pairs2.into();
+
+        let mut delta = clock1.clone();
+        delta -= clock2.clone();
+
+        delta == clock1.clone() - clock2.clone()
+            && (clock1.clone() + clock2.clone()) - clock2 == delta
+    }
+
+    #[test]
+    fn operators() {
+        let a: VClock<i8, usize> = vec![(1, 10), (2, 5)].into();
+        let b: VClock<i8, usize> = vec![(2, 20), (3, 30)].into();
+
+        let merged = a.clone() | b.clone();
+        let common = a & b.clone();
+        let delta = b - common;
+
+        assert!(merged == vec![(1, 10), (2, 20), (3, 30)].into());
+        assert!(delta == vec![(2, 20), (3, 30)].into());
+    }
+
+    #[quickcheck]
+    fn test_changes_since(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs1.into();
+        let mut checkpoint = clock.clone() & pairs2.into();
+
+        checkpoint.apply_all(clock.changes_since(&checkpoint));
+        checkpoint == clock
+    }
+
+    #[test]
+    fn changes_since() {
+        let clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let checkpoint: VClock<i8, usize> = vec![(1, 10), (2, 15)].into();
+
+        let changes = clock.changes_since(&checkpoint);
+        assert!(changes == vec![Dot::new(2, 20), Dot::new(3, 30)]);
+        assert!(clock.changes_since(&clock).is_empty());
+    }
+
+    #[test]
+    fn max_sum_counters() {
+        let clock: VClock<i8, usize> = vec![(1, 10), (2, 30), (3, 20)].into();
+        assert_eq!(Some(30), clock.max_counter());
+        assert_eq!(60, clock.sum_counters());
+
+        let empty = VClock::<i8, usize>::default();
+        assert_eq!(None, empty.max_counter());
+        assert_eq!(0, empty.sum_counters());
+    }
+
+    #[quickcheck]
+    fn test_max_sum_counters_monotonic(pairs1: Vec<(i8, u8)>, pairs2: Vec<(i8, u8)>) -> bool {
+        let clock1: VClock<i8, u64> = pairs1
+            .into_iter()
+            .map(|(a, c)| Dot::new(a, c as u64))
+            .collect();
+        let clock2: VClock<i8, u64> = pairs2
+            .into_iter()
+            .map(|(a, c)| Dot::new(a, c as u64))
+            .collect();
+        let merged = clock1.clone() | clock2;
+
+        merged.max_counter() >= clock1.max_counter()
+            && merged.sum_counters() >= clock1.sum_counters()
+    }
+
+    #[test]
+    fn combinators() {
+        let clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+
+        assert_eq!(60, clock.fold_actors(0, |acc, _, c| acc + c));
+        assert!(clock.map_counters(|_, c| *c as u8 / 10) == vec![(1, 1), (2, 2), (3, 3)].into());
+        assert_eq!(40, clock.filter_actors(|a, _| *a != 2).sum_counters());
+    }
+
+    #[test]
+    fn zip_with() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let clock2: VClock<i8, usize> = vec![(2, 5), (3, 30)].into();
+
+        let summed = clock1.zip_with(&clock2, |c1, c2| c1 + c2);
+        assert!(summed == vec![(1, 10), (2, 25), (3, 30)].into());
+    }
+
+    #[quickcheck]
+    fn test_zip_with_max_is_merge(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs1.into();
+        let clock2: VClock<i8, usize> = pairs2.into();
+
+        let zipped = clock1.zip_with(&clock2, |c1, c2| *c1.max(c2));
+        zipped == clock1 | clock2
+    }
+
+    #[quickcheck]
+    fn test_to_vec_roundtrip(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        let pairs = clock.to_vec();
+        pairs.windows(2).all(|w| w[0].0 < w[1].0) && VClock::from_vec(pairs) == clock
+    }
+
+    #[test]
+    fn to_vec() {
+        let clock = VClock::<i8, usize>::from_vec(vec![(2, 20), (1, 10), (2, 25)]);
+        assert_eq!(vec![(1, 10), (2, 25)], clock.to_vec());
+    }
+
+    #[quickcheck]
+    fn to_dot_vec(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        let dots = clock.to_dot_vec();
+
+        dots.len() == clock.len()
+            && dots.windows(2).all(|w| w[0].actor < w[1].actor)
+            && VClock::from_dot_vec(dots) == clock
+    }
+
+    #[test]
+    fn advance_actor() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10)].into();
+
+        assert!(clock.advance_actor(1, 15));
+        assert!(!clock.advance_actor(1, 12));
+        assert!(!clock.advance_actor(1, 15));
+        assert!(clock.advance_actor(2, 3));
+        assert!(!clock.advance_actor(3, 0));
+
+        assert!(clock == vec![(1, 15), (2, 3)].into());
+    }
+
+    #[quickcheck]
+    fn test_advance_actor(pairs: Vec<(i8, usize)>, actor: i8, to: usize) -> bool {
+        let mut clock: VClock<i8, usize> = pairs.into();
+        let before = clock.dot_or_zero(&actor).counter;
+
+        let advanced = clock.advance_actor(actor, to);
+        let after = clock.dot_or_zero(&actor).counter;
+        advanced == (to > before) && after == before.max(to)
+    }
+
+    #[quickcheck]
+    fn test_advance_all(pairs: Vec<(i8, u16)>) -> bool {
+        let mut clock: VClock<i8, usize> = pairs
+            .into_iter()
+            .map(|(a, c)| (a, c as usize))
+            .collect::<Vec<_>>()
+            .into();
+        let old = clock.clone();
+
+        clock.advance_all();
+        old.is_empty() || clock.dominates(&old)
+    }
+
+    #[test]
+    fn test_advance_all_by() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        clock.advance_all_by(5);
+        assert!(clock == vec![(1, 15), (2, 25)].into());
+
+        let mut empty = VClock::<i8, usize>::default();
+        empty.advance_all_by(5);
+        assert!(empty.is_empty());
+    }
+
+    #[quickcheck]
+    fn test_diff(pairs1: Vec<(i8, usize)>, pairs2: Vec<(i8, usize)>) -> bool {
+        let mut a: VClock<i8, usize> = pairs1.into();
+        let mut b: VClock<i8, usize> = pairs2.into();
+
+        let (a_has, b_has) = a.diff(&b);
+        b.merge(a_has);
+        a.merge(b_has);
+        a == b
+    }
+
+    #[test]
+    fn diff() {
+        let a: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let b: VClock<i8, usize> = vec![(2, 25), (3, 30)].into();
+
+        let (a_has, b_has) = a.diff(&b);
+        assert!(a_has == vec![(1, 10)].into());
+        assert!(b_has == vec![(2, 25), (3, 30)].into());
+    }
+
+    #[test]
+    fn update_actor() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10)].into();
+
+        assert_eq!(20, clock.update_actor(1, |c| c * 2));
+        assert_eq!(15, clock.update_actor(2, |c| c.max(15)));
+        assert_eq!(15, clock.update_actor(2, |c| c.max(5)));
+        assert!(clock == vec![(1, 20), (2, 15)].into());
+    }
+
+    #[quickcheck]
+    fn test_compact_roundtrip(pairs: Vec<(i8, u64)>) -> bool {
+        let clock: VClock<i8, u64> = pairs.into();
+        VClock::decode_compact(&clock.encode_compact()).is_ok_and(|c| c == clock)
+    }
+
+    #[test]
+    fn compact_smaller_than_json() {
+        for len in 3..=10u8 {
+            let clock: VClock<u8, u64> = (0..len).map(|a| Dot::new(a, 1000 + a as u64)).collect();
+            let json = serde_json::to_vec(&clock.to_vec()).unwrap();
+            assert!(clock.encode_compact().len() * 2 < json.len());
+        }
+    }
+
+    #[test]
+    fn compact_errors() {
+        assert!(matches!(
+            VClock::<u8, u64>::decode_compact(&[1, 2, 1, 10, 1, 20]),
+            Err(DecodeError::DuplicateActor)
+        ));
+        assert!(matches!(
+            VClock::<u8, u64>::decode_compact(&[1, 2, 1, 10]),
+            Err(DecodeError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            VClock::<u8, u64>::decode_compact(&[]),
+            Err(DecodeError::UnexpectedEnd)
+        ));
+    }
+
+    #[cfg(feature = "base64")]
+    #[quickcheck]
+    fn test_base64_roundtrip(pairs: Vec<(i8, u64)>) -> bool {
+        let clock: VClock<i8, u64> = pairs.into();
+        let text = clock.to_base64();
+        text.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && VClock::from_base64(&text).is_ok_and(|c| c == clock)
+    }
+
+    #[cfg(feature = "rayon")]
+    #[quickcheck]
+    fn test_parallel_merge(pairs: Vec<(i8, usize)>, others: Vec<Vec<(i8, usize)>>) -> bool {
+        let mut sequential: VClock<i8, usize> = pairs.into();
+        let mut parallel = sequential.clone();
+        let others: Vec<VClock<i8, usize>> = others.into_iter().map(|o| o.into()).collect();
+
+        for other in others.iter() {
+            sequential.merge(other.clone());
+        }
+        parallel.parallel_merge(others);
+
+        parallel == sequential
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_merge_large() {
+        let others: Vec<VClock<u32, u64>> = (0..8u64)
+            .map(|i| {
+                (0..2_000u32)
+                    .map(|a| Dot::new(a, (a as u64 * 7 + i * 13) % 100))
+                    .collect()
+            })
+            .collect();
+
+        let mut sequential = VClock::<u32, u64>::default();
+        for other in others.iter() {
+            sequential.merge(other.clone());
+        }
+
+        let mut parallel = VClock::<u32, u64>::default();
+        parallel.parallel_merge(others);
+        assert!(parallel == sequential);
+    }
+
+    #[cfg(feature = "std")]
+    #[quickcheck]
+    fn test_hashmap_roundtrip(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        let map = clock.to_hashmap();
+
+        map.len() == clock.len()
+            && clock.iter().all(|d| map.get(&d.actor) == Some(&d.counter))
+            && VClock::from_hashmap(map) == clock
+    }
+
+    #[test]
+    fn test_merge_with_policy() {
+        let clock: VClock<i8, usize> = vec![(1, 10), (2, 20), (3, 30)].into();
+        let other: VClock<i8, usize> = vec![(1, 15), (2, 5), (4, 40)].into();
+
+        let mut max = clock.clone();
+        max.merge_with_policy(other.clone(), &crate::MaxMerge);
+        assert!(max == vec![(1, 15), (2, 20), (3, 30), (4, 40)].into());
+
+        let mut min = clock.clone();
+        min.merge_with_policy(other.clone(), &MinMerge);
+        assert!(min == vec![(1, 10), (2, 5)].into());
+
+        let mut trusted = clock.clone();
+        trusted.merge_with_policy(other, &ActorPriorityMerge::new([1]));
+        assert!(trusted == vec![(1, 15), (2, 20), (3, 30)].into());
+    }
+
+    #[quickcheck]
+    fn test_max_merge_policy(pairs: Vec<(i8, usize)>, others: Vec<(i8, usize)>) -> bool {
+        let mut clock: VClock<i8, usize> = pairs.clone().into();
+        let mut merged: VClock<i8, usize> = pairs.into();
+
+        clock.merge_with_policy(others.clone().into(), &crate::MaxMerge);
+        merged.merge(others.into());
+        clock == merged
+    }
+
+    #[test]
+    fn test_validate_op_display() {
+        let clock: VClock<i8, usize> = vec![(1, 10)].into();
+        assert!(clock.validate_op(&Dot::new(1, 11)).is_ok());
+
+        let error = clock.validate_op(&Dot::new(1, 15)).unwrap_err();
+        assert_eq!("1:11..15", error.to_string());
+    }
+
+    #[test]
+    fn test_strict_apply() {
+        let mut clock: VClock<i8, usize> = vec![(1, 10)].into();
+        assert_eq!(Ok(()), clock.strict_apply(Dot::new(1, 10)));
+        assert_eq!(Ok(()), clock.strict_apply(Dot::new(1, 11)));
+        assert_eq!(Ok(()), clock.strict_apply(Dot::new(2, 1)));
+        assert_eq!(
+            Err(StaleOpError {
+                actor: 1,
+                counter: 5,
+                current: 11
+            }),
+            clock.strict_apply(Dot::new(1, 5))
+        );
+
+        assert!(clock == vec![(1, 11), (2, 1)].into());
+    }
+
+    #[quickcheck]
+    fn test_strict_apply_matches_apply(pairs: Vec<(i8, usize)>, dot: (i8, usize)) -> bool {
+        let dot: Dot<i8, usize> = dot.into();
+        let mut strict: VClock<i8, usize> = pairs.into();
+        let mut clock = strict.clone();
+
+        let stale = strict.strict_apply(dot).is_err();
+        clock.apply(dot);
+        stale == (dot.counter < strict.dot_or_zero(&dot.actor).counter) && strict == clock
+    }
+
+    #[quickcheck]
+    fn test_validate_integrity(pairs: Vec<(i8, usize)>) -> bool {
+        let clock: VClock<i8, usize> = pairs.into();
+        clock.validate_integrity().is_ok()
+    }
+
+    #[test]
+    fn test_validate_integrity_negative_counter() {
+        let clock: VClock<i8, i64> = vec![(1, 10), (2, -5), (3, -1)].into();
+        assert_eq!(
+            Err(VClockIntegrityError::NegativeCounter(2)),
+            clock.validate_integrity()
+        );
+    }
+
+    #[test]
+    fn test_validate_integrity_unordered_actors() {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        struct Unordered(u8);
+
+        impl PartialOrd for Unordered {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Unordered {
+            fn cmp(&self, _: &Self) -> core::cmp::Ordering {
+                core::cmp::Ordering::Less
+            }
+        }
+
+        impl crate::Zero for Unordered {
+            fn zero() -> Self {
+                Unordered(0)
+            }
+        }
+
+        impl Actor for Unordered {}
+
+        let clock: VClock<Unordered, usize> = vec![(Unordered(1), 10), (Unordered(2), 20)].into();
+        assert_eq!(
+            Err(VClockIntegrityError::UnorderedActors(Unordered(2))),
+            clock.validate_integrity()
+        );
+    }
+
+    fn hash_of(clock: &VClock<i8, usize>) -> u64 {
+        use core::hash::Hasher;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        clock.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[quickcheck]
+    fn test_hash_consistent_with_eq(pairs: Vec<(i8, usize)>) -> bool {
+        let clock1: VClock<i8, usize> = pairs.into();
+        let mut dots: Vec<Dot<i8, usize>> = clock1.iter().collect();
+        dots.reverse();
+        let clock2: VClock<i8, usize> = dots.into_iter().collect();
+        clock1 == clock2 && hash_of(&clock1) == hash_of(&clock2)
+    }
+
+    #[test]
+    fn hash_differs() {
+        let clock1: VClock<i8, usize> = vec![(1, 10), (2, 20)].into();
+        let clock2: VClock<i8, usize> = vec![(1, 20), (2, 10)].into();
+        assert_ne!(hash_of(&clock1), hash_of(&clock2));
+    }
 }
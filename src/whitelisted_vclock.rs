@@ -0,0 +1,189 @@
+use alloc::collections::BTreeSet;
+use core::fmt::{Debug, Display};
+
+use crate::{Actor, CmRDT, Counter, CvRDT, Dot, VClock};
+
+/// A vclock which only accepts the dots of a known set of actors.
+///
+/// `apply_checked` and `merge_checked` reject the dots of unknown actors, while the `CmRDT`
+/// and `CvRDT` operations silently drop them, so the clock never grows beyond its actors.
+pub struct WhitelistedVClock<A: Actor, C: Counter> {
+    allowed: BTreeSet<A>,
+    clock: VClock<A, C>,
+}
+
+/// The error returned when a dot belongs to an actor which is not allowed.
+#[derive(Debug, PartialEq)]
+pub struct UnknownActorError<A>(pub A);
+
+impl<A: Display> Display for UnknownActorError<A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the actor {} is not allowed", self.0)
+    }
+}
+
+impl<A: Debug + Display> core::error::Error for UnknownActorError<A> {}
+
+//
+// Constructors
+//
+
+impl<A: Actor, C: Counter> FromIterator<A> for WhitelistedVClock<A, C> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<A: Actor, C: Counter> From<WhitelistedVClock<A, C>> for VClock<A, C> {
+    fn from(clock: WhitelistedVClock<A, C>) -> Self {
+        clock.clock
+    }
+}
+
+//
+// CvRDT and CmRDT
+//
+
+impl<A: Actor + Debug + Display, C: Counter> CvRDT for WhitelistedVClock<A, C> {
+    type Validation = UnknownActorError<A>;
+
+    fn validate_merge(&self, other: &Self) -> Result<(), Self::Validation> {
+        self.validate_actors(other.clock.actors())
+    }
+
+    /// Merges another clock, dropping the dots of the actors which are not allowed.
+    fn merge(&mut self, other: Self) {
+        let allowed = &self.allowed;
+        self.clock
+            .merge(other.clock.filter_actors(|a, _| allowed.contains(a)));
+    }
+}
+
+impl<A: Actor + Debug + Display, C: Counter + Debug + Display> CmRDT for WhitelistedVClock<A, C> {
+    type Op = Dot<A, C>;
+    type Validation = UnknownActorError<A>;
+
+    fn validate_op(&self, op: &Self::Op) -> Result<(), Self::Validation> {
+        self.validate_actors(core::iter::once(&op.actor))
+    }
+
+    /// Applies a dot, dropping it if its actor is not allowed.
+    fn apply(&mut self, op: Self::Op) {
+        if self.is_allowed(&op.actor) {
+            self.clock.merge(op.into());
+        }
+    }
+}
+
+//
+// Implementation
+//
+
+impl<A: Actor, C: Counter> WhitelistedVClock<A, C> {
+    /// Constructs a new, empty clock which accepts the dots of the given actors.
+    pub fn new(allowed: impl IntoIterator<Item = A>) -> Self {
+        Self {
+            allowed: allowed.into_iter().collect(),
+            clock: VClock::default(),
+        }
+    }
+
+    /// Determines if the dots of an actor are accepted by the clock.
+    pub fn is_allowed(&self, actor: &A) -> bool {
+        self.allowed.contains(actor)
+    }
+
+    /// Returns the actors accepted by the clock.
+    pub fn allowed(&self) -> &BTreeSet<A> {
+        &self.allowed
+    }
+
+    /// Returns the underlying clock.
+    pub fn clock(&self) -> &VClock<A, C> {
+        &self.clock
+    }
+
+    /// Applies a dot, or returns an error if its actor is not allowed.
+    pub fn apply_checked(&mut self, dot: Dot<A, C>) -> Result<(), UnknownActorError<A>> {
+        self.validate_actors(core::iter::once(&dot.actor))?;
+        self.clock.merge(dot.into());
+        Ok(())
+    }
+
+    /// Merges another clock, or returns an error if it holds a dot of an actor which is not
+    /// allowed. The clock is left unchanged on error.
+    pub fn merge_checked(&mut self, other: VClock<A, C>) -> Result<(), UnknownActorError<A>> {
+        self.validate_actors(other.actors())?;
+        self.clock.merge(other);
+        Ok(())
+    }
+
+    fn validate_actors<'a>(
+        &self,
+        mut actors: impl Iterator<Item = &'a A>,
+    ) -> Result<(), UnknownActorError<A>>
+    where
+        A: 'a,
+    {
+        match actors.find(|a| !self.is_allowed(a)) {
+            Some(a) => Err(UnknownActorError(a.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_checked() {
+        let mut clock: WhitelistedVClock<i8, usize> = WhitelistedVClock::new([1, 2]);
+        assert_eq!(Ok(()), clock.apply_checked(Dot::new(1, 10)));
+        assert_eq!(
+            Err(UnknownActorError(3)),
+            clock.apply_checked(Dot::new(3, 10))
+        );
+
+        assert!(clock.clock() == &vec![(1, 10)].into());
+    }
+
+    #[test]
+    fn test_merge_checked() {
+        let mut clock: WhitelistedVClock<i8, usize> = [1, 2].into_iter().collect();
+        assert_eq!(Ok(()), clock.merge_checked(vec![(1, 10), (2, 20)].into()));
+        assert_eq!(
+            Err(UnknownActorError(3)),
+            clock.merge_checked(vec![(1, 30), (3, 30)].into())
+        );
+
+        assert!(clock.clock() == &vec![(1, 10), (2, 20)].into());
+    }
+
+    #[test]
+    fn test_apply_drops_unknown() {
+        let mut clock: WhitelistedVClock<i8, usize> = WhitelistedVClock::new([1]);
+        assert!(clock.validate_op(&Dot::new(2, 5)).is_err());
+
+        clock.apply(Dot::new(1, 5));
+        clock.apply(Dot::new(2, 5));
+        assert!(clock.clock() == &vec![(1, 5)].into());
+    }
+
+    #[test]
+    fn test_merge_drops_unknown() {
+        let mut clock: WhitelistedVClock<i8, usize> = WhitelistedVClock::new([1, 2]);
+        let mut other: WhitelistedVClock<i8, usize> = WhitelistedVClock::new([2, 3]);
+        other.apply(Dot::new(2, 20));
+        other.apply(Dot::new(3, 30));
+
+        assert_eq!(Err(UnknownActorError(3)), clock.validate_merge(&other));
+
+        clock.merge(other);
+        assert!(VClock::from(clock) == vec![(2, 20)].into());
+    }
+}